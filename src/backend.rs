@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use std::env;
+use std::io::{self, Write};
+
+use termion;
+use tui::backend::{Backend, RawBackend};
+use tui::buffer::Cell;
+use tui::layout::Rect;
+use unicode_width::UnicodeWidthStr;
+
+use markup;
+
+/// Raw terminal backend that can rewrite what the widgets drew before it reaches the terminal.
+pub struct CvBackend {
+    inner: RawBackend,
+    /// Replace everything outside of ASCII, for clients that cannot display it
+    ascii: bool,
+    /// Cells holding a double-width grapheme on the terminal, which also covers the next cell
+    wide: HashSet<(u16, u16)>,
+}
+
+impl CvBackend {
+    pub fn new(ascii: bool) -> Result<CvBackend, io::Error> {
+        Ok(CvBackend {
+            inner: RawBackend::new()?,
+            ascii,
+            wide: HashSet::new(),
+        })
+    }
+
+    /// Puts the text in the clipboard of the terminal with the OSC 52 escape sequence, which
+    /// also works through SSH since the terminal is the one doing it.
+    pub fn copy(&mut self, text: &str) -> Result<(), io::Error> {
+        write!(self.inner, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+        Write::flush(&mut self.inner)
+    }
+}
+
+impl Backend for CvBackend {
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), io::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        // The raw backend only moves the cursor for a cell that does not follow the previous
+        // one, and starts as if it had just drawn the first cell: put the cursor right after
+        // it, or a change starting at the second cell, like a block title, is drawn elsewhere
+        write!(self.inner, "\x1b[1;2H")?;
+        let mut cells = Vec::new();
+        for (x, y, cell) in content {
+            // The second column of a double-width grapheme is left to it: skipping the cell also
+            // has the raw backend move the cursor to the next one
+            if x > 0 && self.wide.contains(&(x - 1, y)) {
+                self.wide.remove(&(x, y));
+                continue;
+            }
+            let symbol = if cell.symbol == markup::CLOSING_BRACE {
+                "}"
+            } else if cell.symbol == markup::BACKSLASH {
+                "\\"
+            } else if self.ascii {
+                ascii(&cell.symbol)
+            } else {
+                &cell.symbol
+            };
+            let columns = columns(symbol);
+            if columns > 1 {
+                self.wide.insert((x, y));
+            } else {
+                self.wide.remove(&(x, y));
+            }
+            let cell = Cell {
+                // A zero-width grapheme on its own still has to clear its cell
+                symbol: String::from(if columns == 0 { " " } else { symbol }),
+                style: cell.style,
+            };
+            cells.push((x, y, cell));
+        }
+        self.inner.draw(cells.iter().map(|&(x, y, ref cell)| (x, y, cell)))
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), io::Error> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> Result<(), io::Error> {
+        self.inner.show_cursor()
+    }
+
+    fn clear(&mut self) -> Result<(), io::Error> {
+        self.wide.clear();
+        self.inner.clear()
+    }
+
+    fn size(&self) -> Result<Rect, io::Error> {
+        self.inner.size()
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        Backend::flush(&mut self.inner)
+    }
+}
+
+/// Columns a symbol takes on the terminal, leaving out the OSC 8 sequences of hyperlinks.
+fn columns(symbol: &str) -> usize {
+    let mut columns = 0;
+    let mut rest = symbol;
+    while let Some(start) = rest.find("\x1b]") {
+        columns += rest[..start].width();
+        rest = match rest[start..].find("\x1b\\") {
+            Some(end) => &rest[start + end + 2..],
+            None => "",
+        };
+    }
+    columns + rest.width()
+}
+
+/// ASCII stand-in for a grapheme, keeping it one column wide so the layout does not move.
+pub fn ascii(symbol: &str) -> &str {
+    if symbol.is_ascii() {
+        return symbol;
+    }
+    match symbol {
+        "│" | "┃" | "║" => "|",
+        "─" | "━" | "═" => "-",
+        "┌" | "┐" | "└" | "┘" | "├" | "┤" | "┬" | "┴" | "┼" => "+",
+        "█" | "▉" | "▊" | "▋" | "▌" | "▇" | "▆" | "▅" => "#",
+        "▍" | "▎" | "▏" | "▄" | "▃" | "▂" | "▁" => "=",
+        "•" | "·" => "*",
+        "←" => "<",
+        "→" => ">",
+        "↑" => "^",
+        "↓" => "v",
+        "–" | "—" => "-",
+        "\u{a0}" | "\u{200b}" => " ",
+        "‘" | "’" => "'",
+        "“" | "”" => "\"",
+        "…" => ".",
+        "à" | "á" | "â" | "ã" | "ä" | "å" => "a",
+        "À" | "Á" | "Â" | "Ã" | "Ä" | "Å" => "A",
+        "ç" => "c",
+        "Ç" => "C",
+        "è" | "é" | "ê" | "ë" => "e",
+        "È" | "É" | "Ê" | "Ë" => "E",
+        "ì" | "í" | "î" | "ï" => "i",
+        "Ì" | "Í" | "Î" | "Ï" => "I",
+        "ñ" => "n",
+        "Ñ" => "N",
+        "ò" | "ó" | "ô" | "õ" | "ö" | "ø" => "o",
+        "Ò" | "Ó" | "Ô" | "Õ" | "Ö" | "Ø" => "O",
+        "ù" | "ú" | "û" | "ü" => "u",
+        "Ù" | "Ú" | "Û" | "Ü" => "U",
+        "ß" => "s",
+        // Braille cells are what the canvas widgets draw with, the blank one included
+        "\u{2800}" => " ",
+        s if s.chars().all(|c| ('\u{2800}'..='\u{28ff}').contains(&c)) => ".",
+        // A letter with combining accents stands for the letter itself
+        s if s.chars().skip(1).all(|c| ('\u{300}'..='\u{36f}').contains(&c)) && s.chars().count() > 1 => {
+            let first = s.chars().next().map_or(0, char::len_utf8);
+            ascii(&s[..first])
+        }
+        _ => "?",
+    }
+}
+
+/// Whether what is printed goes to a terminal that should get colors, see https://no-color.org.
+pub fn supports_color() -> bool {
+    termion::is_tty(&io::stdout()) && env::var_os("NO_COLOR").is_none() && env::var("TERM").map_or(true, |term| term != "dumb")
+}
+
+/// Guesses from the environment whether the terminal understands OSC 8 hyperlinks.
+pub fn supports_hyperlinks() -> bool {
+    let var = |name: &str| env::var(name).unwrap_or_default();
+    if ["iTerm.app", "WezTerm", "vscode", "Hyper", "ghostty"].contains(&var("TERM_PROGRAM").as_str()) {
+        return true;
+    }
+    if var("VTE_VERSION").parse::<u32>().map(|version| version >= 5000).unwrap_or(false) {
+        return true;
+    }
+    ["KITTY_WINDOW_ID", "WT_SESSION", "KONSOLE_VERSION"].iter().any(|name| env::var_os(name).is_some())
+        || ["kitty", "alacritty", "foot"].iter().any(|term| var("TERM").contains(term))
+}
+
+const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clipboard_text_is_base64_encoded() {
+        // The examples of RFC 4648, each length of the last chunk padded
+        for &(text, encoded) in &[("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="),
+                                  ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")] {
+            assert_eq!(base64(text.as_bytes()), encoded);
+        }
+        assert_eq!(base64("daisy@example.com".as_bytes()), "ZGFpc3lAZXhhbXBsZS5jb20=");
+        assert_eq!(base64("Zürich ✓".as_bytes()), "WsO8cmljaCDinJM=");
+        assert_eq!(base64(&[0xfb, 0xff, 0xbf]), "+/+/");
+    }
+}
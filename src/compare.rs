@@ -0,0 +1,90 @@
+use std::cmp::Reverse;
+
+use resume::{Job, Resume};
+
+/// Two resumes shown side by side: two people, or two versions of the resume of one person.
+pub struct Comparison {
+    pub people: [Resume; 2],
+    /// What each side is called: the names, or the files for two versions of a resume
+    pub titles: [String; 2],
+    /// Whether the experience is shown, rather than the skills
+    pub experience: bool,
+    /// Whether what changed from the left to the right is shown over them
+    pub diff: bool,
+    /// Lines of the changes scrolled past
+    pub scroll: u16,
+}
+
+/// A skill of either side, with its level on each side that has it.
+pub struct SkillPair<'a> {
+    pub name: &'a str,
+    pub levels: [Option<u16>; 2],
+}
+
+impl<'a> SkillPair<'a> {
+    /// How much higher the level is on the right, when both sides have the skill.
+    pub fn difference(&self) -> Option<i32> {
+        match self.levels {
+            [Some(left), Some(right)] => Some(i32::from(right) - i32::from(left)),
+            _ => None,
+        }
+    }
+}
+
+/// A job of either side, next to the same one on the other side when it has it.
+pub struct JobPair<'a> {
+    pub jobs: [Option<&'a Job>; 2],
+}
+
+impl Comparison {
+    /// Compares two resumes, called by the names unless they are the same, as for two versions
+    /// of a resume, which are then called by `sources`.
+    pub fn new(people: [Resume; 2], sources: [&str; 2]) -> Comparison {
+        let titles = if people[0].name == people[1].name {
+            [String::from(sources[0]), String::from(sources[1])]
+        } else {
+            [people[0].name.clone(), people[1].name.clone()]
+        };
+        Comparison {
+            people,
+            titles,
+            experience: false,
+            diff: false,
+            scroll: 0,
+        }
+    }
+
+    /// The skills of both sides, those they share first in the order of the left side, then
+    /// those only on the left and those only on the right.
+    pub fn skills(&self) -> Vec<SkillPair<'_>> {
+        let [ref left, ref right] = self.people;
+        let level = |resume: &Resume, name: &str| resume.skills.iter()
+            .find(|skill| skill.name.to_lowercase() == name.to_lowercase())
+            .map(|skill| skill.level);
+        let mut pairs = left.skills.iter()
+            .map(|skill| SkillPair { name: &skill.name, levels: [Some(skill.level), level(right, &skill.name)] })
+            .collect::<Vec<SkillPair>>();
+        pairs.sort_by_key(|pair| pair.levels[1].is_none());
+        pairs.extend(right.skills.iter()
+            .filter(|skill| level(left, &skill.name).is_none())
+            .map(|skill| SkillPair { name: &skill.name, levels: [None, Some(skill.level)] }));
+        pairs
+    }
+
+    /// The jobs of both sides, the same role at the same company side by side, most recent first.
+    pub fn jobs(&self) -> Vec<JobPair<'_>> {
+        let [ref left, ref right] = self.people;
+        let mut pairs = left.experience.iter()
+            .map(|job| JobPair { jobs: [Some(job), right.experience.iter().find(|other| job.is_same(other))] })
+            .collect::<Vec<JobPair>>();
+        pairs.extend(right.experience.iter()
+            .filter(|job| !left.experience.iter().any(|other| job.is_same(other)))
+            .map(|job| JobPair { jobs: [None, Some(job)] }));
+        // Still going on first, then by when they ended and started
+        pairs.sort_by_key(|pair| {
+            let job = pair.jobs[0].or(pair.jobs[1]).map(|job| job.period);
+            Reverse(job.map(|period| (period.end.is_none(), period.end, period.start)))
+        });
+        pairs
+    }
+}
@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::Path;
+
+use toml;
+
+use resume::Resume;
+
+/// Extension of resume data files.
+const EXTENSION: &str = "toml";
+
+/// A resume from a data file, in TOML with the fields of `Resume`. Dates are a year, such as
+/// `2017`, or a month, such as `"2017-03"`, jobs and entries giving them as `start` and `end`.
+pub fn load(path: &Path) -> Result<Resume, String> {
+    let text = fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+    parse(&text, &path.display().to_string())
+}
+
+/// A resume from the contents of a data file, `source` saying where they come from in errors.
+pub fn parse(text: &str, source: &str) -> Result<Resume, String> {
+    let resume: Resume = toml::from_str(text).map_err(|error| format!("Cannot load {}: {}", source, error))?;
+    if let Some(skill) = resume.skills.iter().find(|skill| skill.level > 100) {
+        return Err(format!("Cannot load {}: the level of {} is {}, it goes from 0 to 100", source, skill.name, skill.level));
+    }
+    Ok(resume)
+}
+
+/// Writes a resume to a data file, in the format `load` reads.
+pub fn save(resume: &Resume, path: &Path) -> Result<(), String> {
+    // Going through a value puts the tables after the plain values, as TOML wants them
+    let text = toml::Value::try_from(resume)
+        .and_then(|value| toml::to_string_pretty(&value))
+        .map_err(|error| format!("Cannot save {}: {}", path.display(), error))?;
+    fs::write(path, text).map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+}
+
+/// Every resume data file of a directory, sorted by file name.
+pub fn load_dir(dir: &Path) -> Result<Vec<Resume>, String> {
+    let entries = fs::read_dir(dir).map_err(|error| format!("Cannot read {}: {}", dir.display(), error))?;
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|extension| extension == EXTENSION))
+        .collect::<Vec<_>>();
+    if paths.is_empty() {
+        return Err(format!("No resume files (*.{}) in {}", EXTENSION, dir.display()));
+    }
+    paths.sort();
+    paths.iter().map(|path| load(path)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skill_levels_go_up_to_100() {
+        let skill = |level: u16| format!("name = \"Ana\"\n\n[[skills]]\nname = \"Rust\"\nlevel = {}\n", level);
+        assert_eq!(parse(&skill(100), "ana.toml").map(|resume| resume.skills[0].level), Ok(100));
+        assert_eq!(parse(&skill(150), "ana.toml").err(),
+                   Some(String::from("Cannot load ana.toml: the level of Rust is 150, it goes from 0 to 100")));
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{self, Visitor};
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// A month of a year, or a whole year when the month is not known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    /// From 1 to 12
+    pub month: Option<u32>,
+}
+
+impl Date {
+    pub fn year(year: i32) -> Date {
+        Date { year, month: None }
+    }
+
+    /// The current month, according to the system clock.
+    pub fn today() -> Date {
+        Date::today_with_day().0
+    }
+
+    /// The current month and day of the month, according to the system clock.
+    pub fn today_with_day() -> (Date, u32) {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        Date::from_timestamp(seconds as i64)
+    }
+
+    /// The month and day of the month of a time given in seconds since 1970.
+    pub fn from_timestamp(seconds: i64) -> (Date, u32) {
+        // Civil calendar from a day count, see http://howardhinnant.github.io/date_algorithms.html
+        let days = seconds.div_euclid(86_400) + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        let date = Date {
+            year: year as i32,
+            month: Some(month as u32),
+        };
+        (date, day as u32)
+    }
+
+    /// The date as it is written in resume files: "2017", or "2017-03" with the month.
+    pub fn code(&self) -> String {
+        match self.month {
+            Some(month) => format!("{}-{:02}", self.year, month),
+            None => self.year.to_string(),
+        }
+    }
+
+    /// A date written as `Display` writes it, such as "Mar 2017" or "2017", the month being
+    /// allowed in full.
+    pub fn parse_written(text: &str) -> Option<Date> {
+        let parts = text.split_whitespace().collect::<Vec<&str>>();
+        let (month, year) = match parts[..] {
+            [month, year] => (MONTHS.iter().position(|name| month.starts_with(name)).map(|index| index as u32 + 1)?, year),
+            [year] => return year.parse().ok().map(Date::year),
+            _ => return None,
+        };
+        year.parse().ok().map(|year| Date { year, month: Some(month) })
+    }
+
+    /// Months since year 0 of the first month this date may stand for.
+    pub fn first_month(&self) -> i32 {
+        self.year * 12 + self.month.unwrap_or(1) as i32 - 1
+    }
+
+    /// Months since year 0 of the last month this date may stand for.
+    pub fn last_month(&self) -> i32 {
+        self.year * 12 + self.month.unwrap_or(12) as i32 - 1
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.month {
+            Some(month) => write!(f, "{} {}", MONTHS[(month as usize - 1) % 12], self.year),
+            None => write!(f, "{}", self.year),
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// A year, such as "2017", or a month of a year, such as "2017-03".
+    fn from_str(text: &str) -> Result<Date, String> {
+        let invalid = || format!("invalid date: {}, use a year such as 2017 or a month such as 2017-03", text);
+        let mut parts = text.trim().splitn(2, '-');
+        let year = parts.next().unwrap_or("").parse::<i32>().map_err(|_| invalid())?;
+        let month = match parts.next() {
+            Some(month) => Some(month.parse::<u32>().ok().filter(|month| (1..=12).contains(month)).ok_or_else(invalid)?),
+            None => None,
+        };
+        Ok(Date { year, month })
+    }
+}
+
+struct DateVisitor;
+
+impl<'de> Visitor<'de> for DateVisitor {
+    type Value = Date;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a year such as 2017 or a month such as \"2017-03\"")
+    }
+
+    fn visit_i64<E: de::Error>(self, year: i64) -> Result<Date, E> {
+        Ok(Date::year(year as i32))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Date, E> {
+        text.parse().map_err(E::custom)
+    }
+}
+
+/// Dates are written in resume files as a year, such as `2017`, or a month, such as `"2017-03"`.
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        deserializer.deserialize_any(DateVisitor)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.month {
+            Some(_) => serializer.serialize_str(&self.code()),
+            None => serializer.serialize_i32(self.year),
+        }
+    }
+}
+
+/// From a start date to an end date, included, or still going on without one.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Period {
+    pub start: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<Date>,
+}
+
+impl Period {
+    pub fn new(start: Date, end: Option<Date>) -> Period {
+        Period { start, end }
+    }
+
+    /// First and last months covered, an ongoing period going on until `today`.
+    pub fn months(&self, today: Date) -> (i32, i32) {
+        let last = match self.end {
+            Some(end) => end.last_month(),
+            None => today.first_month(),
+        };
+        (self.start.first_month(), last.max(self.start.first_month()))
+    }
+
+    /// Number of months covered, the current one included for a period still going on.
+    pub fn length(&self, today: Date) -> i32 {
+        let (first, last) = self.months(today);
+        last - first + 1
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.end {
+            Some(end) if end == self.start => write!(f, "{}", self.start),
+            Some(end) => write!(f, "{} - {}", self.start, end),
+            None => write!(f, "{} - current", self.start),
+        }
+    }
+}
+
+/// Number of months covered by some periods, the months where they overlap counting once.
+pub fn total(periods: &[Period], today: Date) -> i32 {
+    let mut months = periods.iter().map(|period| period.months(today)).collect::<Vec<(i32, i32)>>();
+    months.sort();
+    let mut total = 0;
+    // First month that is not counted yet
+    let mut next = i32::MIN;
+    for (first, last) in months {
+        let first = first.max(next);
+        if last >= first {
+            total += last - first + 1;
+            next = last + 1;
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn month(year: i32, month: u32) -> Date {
+        Date { year, month: Some(month) }
+    }
+
+    #[test]
+    fn dates_are_read_as_written_in_files() {
+        assert_eq!("2017".parse(), Ok(Date::year(2017)));
+        assert_eq!(" 2017-03 ".parse(), Ok(month(2017, 3)));
+        assert!("2017-13".parse::<Date>().is_err());
+        assert!("2017-00".parse::<Date>().is_err());
+        assert!("March 2017".parse::<Date>().is_err());
+        assert_eq!(month(2017, 3).code(), "2017-03");
+        assert_eq!(Date::year(2017).code(), "2017");
+    }
+
+    #[test]
+    fn dates_are_read_as_displayed() {
+        assert_eq!(Date::parse_written("Mar 2017"), Some(month(2017, 3)));
+        assert_eq!(Date::parse_written("September 2016"), Some(month(2016, 9)));
+        assert_eq!(Date::parse_written(" 2015 "), Some(Date::year(2015)));
+        assert_eq!(Date::parse_written(&month(2017, 12).to_string()), Some(month(2017, 12)));
+        assert_eq!(Date::parse_written("Mär 2017"), None);
+        assert_eq!(Date::parse_written("Mar"), None);
+        assert_eq!(Date::parse_written("Mar 2017 2018"), None);
+        assert_eq!(Date::parse_written(""), None);
+    }
+
+    #[test]
+    fn years_stand_for_all_their_months() {
+        assert_eq!(Date::year(2017).first_month(), month(2017, 1).first_month());
+        assert_eq!(Date::year(2017).last_month(), month(2017, 12).last_month());
+        assert_eq!(month(2018, 1).first_month() - month(2017, 12).last_month(), 1);
+    }
+
+    #[test]
+    fn periods_count_their_first_and_last_months() {
+        assert_eq!(Period::new(month(2017, 3), Some(month(2017, 3))).length(month(2020, 1)), 1);
+        assert_eq!(Period::new(month(2016, 11), Some(month(2017, 2))).length(month(2020, 1)), 4);
+        assert_eq!(Period::new(Date::year(2015), Some(Date::year(2016))).length(month(2020, 1)), 24);
+        assert_eq!(Period::new(month(2019, 11), None).length(month(2020, 1)), 3);
+        // An ongoing period that starts after today still counts its first month
+        assert_eq!(Period::new(month(2020, 6), None).length(month(2020, 1)), 1);
+    }
+
+    #[test]
+    fn overlapping_months_count_once() {
+        let today = month(2020, 1);
+        let periods = [
+            Period::new(month(2015, 1), Some(month(2015, 12))),
+            Period::new(month(2015, 7), Some(month(2016, 6))),
+            Period::new(month(2018, 1), Some(month(2018, 3))),
+            Period::new(month(2018, 2), Some(month(2018, 2))),
+        ];
+        assert_eq!(total(&periods, today), 18 + 3);
+        assert_eq!(total(&[], today), 0);
+    }
+
+    #[test]
+    fn timestamps_fall_on_their_civil_day() {
+        assert_eq!(Date::from_timestamp(0), (month(1970, 1), 1));
+        // 2020-02-29T12:00:00Z
+        assert_eq!(Date::from_timestamp(1_582_977_600), (month(2020, 2), 29));
+        // 1969-12-31T23:59:59Z
+        assert_eq!(Date::from_timestamp(-1), (month(1969, 12), 31));
+    }
+}
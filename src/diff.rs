@@ -0,0 +1,290 @@
+use termion::{color, style};
+
+use i18n::Lang;
+use locale::Locale;
+use markup;
+use resume::{Job, Resume, Skill};
+
+/// What happened to a word of a paragraph.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Edit {
+    Kept,
+    Added,
+    Removed,
+}
+
+/// A difference between two versions of a resume.
+pub enum Change<'a> {
+    /// A job only in the new version
+    AddedJob(&'a Job),
+    /// A job only in the old version
+    RemovedJob(&'a Job),
+    /// A job of the new version whose dates were different in the old one
+    Dates(&'a Job, &'a Job),
+    AddedSkill(&'a Skill),
+    RemovedSkill(&'a Skill),
+    /// A skill of the new version whose level was different in the old one
+    Level(&'a Skill, &'a Skill),
+    /// A paragraph of a job or of the About section, word by word: added or removed as a
+    /// whole, or edited
+    Bullet(String, Vec<(Edit, String)>),
+}
+
+/// How a piece of the report is shown: the colors of a terminal or of the application, or
+/// markers where there are no colors.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tint {
+    Heading,
+    /// A whole item that is new, or gone
+    Added,
+    Removed,
+    Changed,
+    /// Words of an edited paragraph
+    Kept,
+    Inserted,
+    Deleted,
+}
+
+/// Operations turning `old` into `new`, keeping their longest common subsequence.
+fn edits<'a, T: PartialEq>(old: &'a [T], new: &'a [T]) -> Vec<(Edit, &'a T)> {
+    // Length of the longest common subsequence of the ends of both, from each position
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] { lengths[i + 1][j + 1] + 1 } else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut edits = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push((Edit::Kept, &old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            edits.push((Edit::Removed, &old[i]));
+            i += 1;
+        } else {
+            edits.push((Edit::Added, &new[j]));
+            j += 1;
+        }
+    }
+    edits
+}
+
+/// The words of two versions of a paragraph, each kept, added or removed.
+fn words(old: &str, new: &str) -> Vec<(Edit, String)> {
+    let old = old.split_whitespace().collect::<Vec<&str>>();
+    let new = new.split_whitespace().collect::<Vec<&str>>();
+    edits(&old, &new).into_iter().map(|(edit, &word)| (edit, String::from(word))).collect()
+}
+
+/// The paragraphs of two versions of a text that were added, removed or edited: those that
+/// are not in both are paired up in order, the ones left over being added or removed. Links
+/// are compared by their label.
+fn bullets<'a>(item: &str, old: &str, new: &str) -> Vec<Change<'a>> {
+    let (old, new) = (markup::plain(old), markup::plain(new));
+    let old = old.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<&str>>();
+    let new = new.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<&str>>();
+    let mut changes = Vec::new();
+    let mut removed: Vec<&str> = Vec::new();
+    let mut added: Vec<&str> = Vec::new();
+    let flush = |removed: &mut Vec<&str>, added: &mut Vec<&str>, changes: &mut Vec<Change<'a>>| {
+        for i in 0..removed.len().max(added.len()) {
+            let before = removed.get(i).cloned().unwrap_or("");
+            let after = added.get(i).cloned().unwrap_or("");
+            changes.push(Change::Bullet(String::from(item), words(before, after)));
+        }
+        removed.clear();
+        added.clear();
+    };
+    for (edit, &line) in edits(&old, &new) {
+        match edit {
+            Edit::Kept => flush(&mut removed, &mut added, &mut changes),
+            Edit::Added => added.push(line),
+            Edit::Removed => removed.push(line),
+        }
+    }
+    flush(&mut removed, &mut added, &mut changes);
+    changes
+}
+
+/// What changed from the old version of a resume to the new one, by section: the skills, the
+/// jobs and the About section, those that did not change being left out.
+pub fn diff<'a>(old: &'a Resume, new: &'a Resume) -> Vec<(&'static str, Vec<Change<'a>>)> {
+    let skill = |resume: &'a Resume, name: &str| resume.skills.iter().find(|skill| skill.name.to_lowercase() == name.to_lowercase());
+    let mut skills = Vec::new();
+    for before in &old.skills {
+        match skill(new, &before.name) {
+            Some(after) if after.level != before.level => skills.push(Change::Level(after, before)),
+            Some(_) => {}
+            None => skills.push(Change::RemovedSkill(before)),
+        }
+    }
+    skills.extend(new.skills.iter().filter(|after| skill(old, &after.name).is_none()).map(Change::AddedSkill));
+
+    let mut experience = Vec::new();
+    for after in &new.experience {
+        match old.experience.iter().find(|before| before.is_same(after)) {
+            Some(before) => {
+                if before.period != after.period {
+                    experience.push(Change::Dates(after, before));
+                }
+                let item = format!("{}, {}", after.role, after.company);
+                experience.extend(bullets(&item, &before.description, &after.description));
+            }
+            None => experience.push(Change::AddedJob(after)),
+        }
+    }
+    experience.extend(old.experience.iter()
+        .filter(|before| !new.experience.iter().any(|after| after.is_same(before)))
+        .map(Change::RemovedJob));
+
+    let about = bullets("About me", &old.about, &new.about);
+    vec![("Skills", skills), ("Experience", experience), ("About me", about)].into_iter()
+        .filter(|(_, changes)| !changes.is_empty())
+        .collect()
+}
+
+/// The report of the changes, one per line, with `paint` giving each piece its look.
+pub fn report<F>(diff: &[(&'static str, Vec<Change>)], lang: Lang, locale: &Locale, paint: F) -> String
+where
+    F: Fn(Tint, &str) -> String,
+{
+    let job = |job: &Job| format!("{}, {} ({})", job.role, job.company, locale.period(job.period));
+    let mut lines = Vec::new();
+    for (section, changes) in diff {
+        lines.push(paint(Tint::Heading, lang.text(section)));
+        for change in changes {
+            lines.push(match *change {
+                Change::AddedJob(added) => paint(Tint::Added, &format!("+ {}", job(added))),
+                Change::RemovedJob(removed) => paint(Tint::Removed, &format!("- {}", job(removed))),
+                Change::Dates(after, before) => paint(Tint::Changed, &format!("~ {}, {}: {} → {}", after.role, after.company,
+                                                                            locale.period(before.period), locale.period(after.period))),
+                Change::AddedSkill(added) => paint(Tint::Added, &format!("+ {}: {}", added.name, added.level)),
+                Change::RemovedSkill(removed) => paint(Tint::Removed, &format!("- {}: {}", removed.name, removed.level)),
+                Change::Level(after, before) => {
+                    let difference = i32::from(after.level) - i32::from(before.level);
+                    let tint = if difference > 0 { Tint::Added } else { Tint::Removed };
+                    format!("{} {}", paint(Tint::Changed, &format!("~ {}: {} → {}", after.name, before.level, after.level)),
+                            paint(tint, &format!("({:+})", difference)))
+                }
+                Change::Bullet(ref item, ref words) => {
+                    let text = |kept: Edit| words.iter()
+                        .filter(|&&(edit, _)| edit == Edit::Kept || edit == kept)
+                        .map(|(_, word)| word.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(" ");
+                    if words.iter().all(|&(edit, _)| edit == Edit::Added) {
+                        paint(Tint::Added, &format!("+ {}: {}", item, text(Edit::Added)))
+                    } else if words.iter().all(|&(edit, _)| edit == Edit::Removed) {
+                        paint(Tint::Removed, &format!("- {}: {}", item, text(Edit::Removed)))
+                    } else {
+                        let edited = words.iter()
+                            .map(|&(edit, ref word)| match edit {
+                                Edit::Kept => paint(Tint::Kept, word),
+                                Edit::Added => paint(Tint::Inserted, word),
+                                Edit::Removed => paint(Tint::Deleted, word),
+                            })
+                            .collect::<Vec<String>>()
+                            .join(" ");
+                        format!("{}\n    {}", paint(Tint::Changed, &format!("~ {}:", item)), edited)
+                    }
+                }
+            });
+        }
+    }
+    lines.join("\n")
+}
+
+/// Colors for a terminal, words that were removed being crossed out.
+pub fn ansi(tint: Tint, text: &str) -> String {
+    match tint {
+        Tint::Heading => format!("{}{}{}", style::Bold, text, style::Reset),
+        Tint::Added | Tint::Inserted => format!("{}{}{}", color::Fg(color::Green), text, color::Fg(color::Reset)),
+        Tint::Removed => format!("{}{}{}", color::Fg(color::Red), text, color::Fg(color::Reset)),
+        Tint::Deleted => format!("{}{}{}{}", color::Fg(color::Red), style::CrossedOut, text, style::Reset),
+        Tint::Changed => format!("{}{}{}", color::Fg(color::Yellow), text, color::Fg(color::Reset)),
+        Tint::Kept => String::from(text),
+    }
+}
+
+/// Markers for output without colors, as in `git diff --word-diff`: `[-removed-]{+added+}`.
+pub fn plain(tint: Tint, text: &str) -> String {
+    match tint {
+        Tint::Inserted => format!("{{+{}+}}", text),
+        Tint::Deleted => format!("[-{}-]", text),
+        _ => String::from(text),
+    }
+}
+
+/// `Paragraph` markup, for the application.
+pub fn styled(tint: Tint, text: &str) -> String {
+    let style = match tint {
+        Tint::Heading => "mod=bold;fg=magenta",
+        Tint::Added | Tint::Inserted => "fg=green",
+        Tint::Removed => "fg=red",
+        Tint::Deleted => "mod=crossed_out;fg=red",
+        Tint::Changed => "fg=yellow",
+        Tint::Kept => return markup::escape(text),
+    };
+    format!("{{{} {}}}", style, markup::escape(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edits written as "=kept -removed +added".
+    fn written<T: ToString>(edits: &[(Edit, T)]) -> String {
+        edits.iter()
+            .map(|(edit, item)| {
+                let sign = match *edit {
+                    Edit::Kept => '=',
+                    Edit::Added => '+',
+                    Edit::Removed => '-',
+                };
+                format!("{}{}", sign, item.to_string())
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn edits_keep_a_longest_common_subsequence() {
+        let old = "ABCBDAB".chars().collect::<Vec<char>>();
+        let new = "BDCABA".chars().collect::<Vec<char>>();
+        let both = edits(&old, &new);
+        assert_eq!(both.iter().filter(|&&(edit, _)| edit == Edit::Kept).count(), 4);
+        let side = |left_out: Edit| both.iter().filter(|&&(edit, _)| edit != left_out).map(|&(_, &c)| c).collect::<String>();
+        assert_eq!(side(Edit::Added), "ABCBDAB");
+        assert_eq!(side(Edit::Removed), "BDCABA");
+        assert_eq!(written(&edits(&old, &[])), "-A -B -C -B -D -A -B");
+        assert_eq!(written(&edits(&[], &new[..2])), "+B +D");
+    }
+
+    #[test]
+    fn paragraphs_are_compared_word_by_word() {
+        assert_eq!(written(&words("Built  web apps for clients", "Built fast web services for clients")),
+                   "=Built +fast =web -apps +services =for =clients");
+    }
+
+    #[test]
+    fn changes_are_reported_by_section() {
+        let old = Resume::builtin();
+        let mut new = old.clone();
+        new.skills[0].level += 5;
+        new.experience.remove(1);
+        new.about = old.about.replacen("knitting", "sewing", 1);
+        let report = report(&diff(&old, &new), Lang::English, Locale::of(Lang::English), plain);
+        let lines = report.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], "Skills");
+        assert!(lines[1].starts_with(&format!("~ {}: {} → {} (+5)", old.skills[0].name, old.skills[0].level, new.skills[0].level)));
+        assert_eq!(lines[2], "Experience");
+        assert!(lines[3].starts_with(&format!("- {}, {} (", old.experience[1].role, old.experience[1].company)));
+        assert_eq!(lines[4], "About me");
+        assert_eq!(lines[5], "~ About me:");
+        assert!(lines[6].contains("[-knitting,-] {+sewing,+}"));
+        assert_eq!(lines.len(), 7);
+    }
+}
@@ -0,0 +1,594 @@
+use termion::event::Key;
+
+use date::{Date, Period};
+use resume::{Entry, Field, Goal, Job, Resume, Skill};
+
+/// What `rust-cv init` asks for, a form each, in order.
+pub const STEPS: [&str; 5] = ["Name", "Contact", "Languages", "Skills", "Jobs"];
+
+/// Sets what an input stands for in a resume, from the text typed in it, or says what is wrong
+/// with the text.
+type Setter = Box<dyn Fn(&mut Resume, &str) -> Result<(), String>>;
+
+/// A list of the resume whose items are edited with a few inputs each, items that can be added
+/// and are removed when all their inputs are emptied.
+#[derive(Clone, Copy, PartialEq)]
+enum List {
+    Information,
+    Languages,
+    Contact,
+    Skills,
+    Jobs,
+    Education,
+    Courses,
+    Projects,
+    Volunteering,
+    Contributions,
+    Goals,
+}
+
+impl List {
+    /// The lists edited in a section, by its index in the tabs, the one items are added to
+    /// first.
+    fn of(section: usize) -> &'static [List] {
+        match section {
+            1 => &[List::Contact, List::Information, List::Languages],
+            2 => &[List::Skills],
+            3 => &[List::Jobs],
+            4 => &[List::Education, List::Courses],
+            5 => &[List::Projects, List::Volunteering, List::Contributions],
+            6 => &[List::Goals],
+            _ => &[],
+        }
+    }
+
+    /// What each item is called, with its number.
+    fn heading(self) -> &'static str {
+        match self {
+            List::Information => "Information {}",
+            List::Languages => "Language {}",
+            List::Contact => "Contact {}",
+            List::Skills => "Skill {}",
+            List::Jobs => "Job {}",
+            List::Education => "Education {}",
+            List::Courses => "Course {}",
+            List::Projects => "Project {}",
+            List::Volunteering => "Volunteer work {}",
+            List::Contributions => "Contribution {}",
+            List::Goals => "Goal {}",
+        }
+    }
+
+    /// What the label and the value of a field stand for.
+    fn labels(self) -> (&'static str, &'static str) {
+        match self {
+            List::Languages => ("Language", "Level"),
+            _ => ("Label", "Value"),
+        }
+    }
+
+    fn fields(self) -> Option<fn(&mut Resume) -> &mut Vec<Field>> {
+        match self {
+            List::Information => Some(|resume| &mut resume.information),
+            List::Languages => Some(|resume| &mut resume.languages),
+            List::Contact => Some(|resume| &mut resume.contact),
+            _ => None,
+        }
+    }
+
+    fn entries(self) -> Option<fn(&mut Resume) -> &mut Vec<Entry>> {
+        match self {
+            List::Education => Some(|resume| &mut resume.education),
+            List::Courses => Some(|resume| &mut resume.courses),
+            List::Projects => Some(|resume| &mut resume.projects),
+            List::Volunteering => Some(|resume| &mut resume.volunteering),
+            List::Contributions => Some(|resume| &mut resume.contributions),
+            _ => None,
+        }
+    }
+
+    fn len(self, resume: &mut Resume) -> usize {
+        match (self.fields(), self.entries(), self) {
+            (Some(fields), _, _) => fields(resume).len(),
+            (_, Some(entries), _) => entries(resume).len(),
+            (_, _, List::Skills) => resume.skills.len(),
+            (_, _, List::Jobs) => resume.experience.len(),
+            _ => resume.objective.goals.len(),
+        }
+    }
+
+    /// Adds an item with nothing in it at the end of the list.
+    fn push(self, resume: &mut Resume) {
+        let text = String::new;
+        match (self.fields(), self.entries(), self) {
+            (Some(fields), _, _) => fields(resume).push(Field { label: text(), value: text() }),
+            (_, Some(entries), _) => entries(resume).push(Entry {
+                title: text(),
+                period: None,
+                skills: Vec::new(),
+                audiences: Vec::new(),
+                description: text(),
+            }),
+            (_, _, List::Skills) => resume.skills.push(Skill {
+                name: text(),
+                category: text(),
+                level: 0,
+                learning: false,
+                audiences: Vec::new(),
+            }),
+            (_, _, List::Jobs) => resume.experience.push(Job {
+                period: Period::new(Date::year(0), None),
+                role: text(),
+                company: text(),
+                location: text(),
+                skills: Vec::new(),
+                audiences: Vec::new(),
+                description: text(),
+            }),
+            _ => resume.objective.goals.push(Goal { text: text(), audiences: Vec::new() }),
+        }
+    }
+
+    fn remove(self, resume: &mut Resume, index: usize) {
+        match (self.fields(), self.entries(), self) {
+            (Some(fields), _, _) => {
+                fields(resume).remove(index);
+            }
+            (_, Some(entries), _) => {
+                entries(resume).remove(index);
+            }
+            (_, _, List::Skills) => {
+                resume.skills.remove(index);
+            }
+            (_, _, List::Jobs) => {
+                resume.experience.remove(index);
+            }
+            _ => {
+                resume.objective.goals.remove(index);
+            }
+        }
+    }
+
+    /// The inputs of an item, filled with what it has.
+    fn inputs(self, resume: &mut Resume, index: usize) -> Vec<Input> {
+        let mut inputs = if let Some(fields) = self.fields() {
+            let field = fields(resume)[index].clone();
+            let (label, value) = self.labels();
+            vec![
+                Input::line(label, &field.label, assign(move |resume| &mut fields(resume)[index].label, required)),
+                Input::line(value, &field.value, assign(move |resume| &mut fields(resume)[index].value, any)),
+            ]
+        } else if let Some(entries) = self.entries() {
+            let entry = entries(resume)[index].clone();
+            let start = entry.period.map(|period| period.start.code()).unwrap_or_default();
+            let end = entry.period.and_then(|period| period.end).map(|end| end.code()).unwrap_or_default();
+            vec![
+                Input::line("Title", &entry.title, assign(move |resume| &mut entries(resume)[index].title, required)),
+                Input::line("Start", &start, Box::new(move |resume, text| {
+                    entries(resume)[index].period = optional_date(text)?.map(|start| Period::new(start, None));
+                    Ok(())
+                })),
+                Input::line("End, empty while it lasts", &end, Box::new(move |resume, text| {
+                    let end = optional_date(text)?;
+                    match entries(resume)[index].period {
+                        Some(ref mut period) => period.end = ending(period.start, end)?,
+                        None if end.is_some() => return Err(String::from("Needs a start")),
+                        None => {}
+                    }
+                    Ok(())
+                })),
+                Input::text("Description", &entry.description, assign(move |resume| &mut entries(resume)[index].description, any)),
+            ]
+        } else if self == List::Skills {
+            let skill = &resume.skills[index];
+            vec![
+                Input::line("Name", &skill.name, assign(move |resume| &mut resume.skills[index].name, required)),
+                Input::line("Level, from 0 to 100", &skill.level.to_string(), assign(move |resume| &mut resume.skills[index].level, level)),
+                Input::line("Category", &skill.category, assign(move |resume| &mut resume.skills[index].category, any)),
+            ]
+        } else if self == List::Jobs {
+            let job = &resume.experience[index];
+            let end = job.period.end.map(|end| end.code()).unwrap_or_default();
+            vec![
+                Input::line("Role", &job.role, assign(move |resume| &mut resume.experience[index].role, required)),
+                Input::line("Company", &job.company, assign(move |resume| &mut resume.experience[index].company, required)),
+                Input::line("Location", &job.location, assign(move |resume| &mut resume.experience[index].location, any)),
+                Input::line("Start", &job.period.start.code(), assign(move |resume| &mut resume.experience[index].period.start, date)),
+                Input::line("End, empty while it lasts", &end, Box::new(move |resume, text| {
+                    let period = &mut resume.experience[index].period;
+                    period.end = ending(period.start, optional_date(text)?)?;
+                    Ok(())
+                })),
+                Input::text("Description", &job.description, assign(move |resume| &mut resume.experience[index].description, any)),
+            ]
+        } else {
+            let goal = &resume.objective.goals[index];
+            vec![Input::line("Goal", &goal.text, assign(move |resume| &mut resume.objective.goals[index].text, required))]
+        };
+        inputs[0].heading = Some((self.heading(), index + 1));
+        for input in &mut inputs {
+            input.item = Some((self, index));
+        }
+        inputs
+    }
+}
+
+/// A setter putting the text, once parsed, in a place of the resume.
+fn assign<P, T>(place: P, parse: fn(&str) -> Result<T, String>) -> Setter
+where
+    P: Fn(&mut Resume) -> &mut T + 'static,
+    T: 'static,
+{
+    Box::new(move |resume, text| {
+        *place(resume) = parse(text)?;
+        Ok(())
+    })
+}
+
+fn any(text: &str) -> Result<String, String> {
+    Ok(String::from(text))
+}
+
+fn required(text: &str) -> Result<String, String> {
+    if text.is_empty() {
+        Err(String::from("Needs a value"))
+    } else {
+        Ok(String::from(text))
+    }
+}
+
+fn date(text: &str) -> Result<Date, String> {
+    optional_date(text)?.ok_or_else(|| String::from("Needs a date"))
+}
+
+fn optional_date(text: &str) -> Result<Option<Date>, String> {
+    if text.is_empty() {
+        Ok(None)
+    } else {
+        text.parse().map(Some)
+    }
+}
+
+/// The end of a period, checking that it does not come before the start.
+fn ending(start: Date, end: Option<Date>) -> Result<Option<Date>, String> {
+    match end {
+        Some(end) if end.last_month() < start.first_month() => Err(String::from("Ends before it starts")),
+        end => Ok(end),
+    }
+}
+
+fn level(text: &str) -> Result<u16, String> {
+    text.parse().ok().filter(|level| *level <= 100).ok_or_else(|| String::from("Use a number from 0 to 100"))
+}
+
+fn name(resume: &Resume) -> Input {
+    Input::line("Name", &resume.name, assign(|resume| &mut resume.name, required))
+}
+
+fn about(resume: &Resume) -> Input {
+    Input::text("About me", &resume.about, assign(|resume| &mut resume.about, any))
+}
+
+/// A form field, with the text typed in it.
+pub struct Input {
+    pub label: &'static str,
+    pub value: String,
+    /// Whether it takes several lines, Enter starting a new one
+    pub multiline: bool,
+    /// What the item it is the first input of is called, with its number
+    pub heading: Option<(&'static str, usize)>,
+    /// What is wrong with the value, once saving was tried
+    pub error: Option<String>,
+    item: Option<(List, usize)>,
+    set: Setter,
+}
+
+impl Input {
+    fn line(label: &'static str, value: &str, set: Setter) -> Input {
+        Input {
+            label,
+            value: String::from(value),
+            multiline: false,
+            heading: None,
+            error: None,
+            item: None,
+            set,
+        }
+    }
+
+    fn text(label: &'static str, value: &str, set: Setter) -> Input {
+        Input {
+            multiline: true,
+            ..Input::line(label, value, set)
+        }
+    }
+}
+
+/// What a key typed in the editor asks for.
+#[derive(PartialEq)]
+pub enum Action {
+    Nothing,
+    Save,
+    Cancel,
+}
+
+/// The form a section of the resume is edited with.
+pub struct Editor {
+    /// The resume being edited, with the items added since
+    resume: Resume,
+    pub inputs: Vec<Input>,
+    pub selected: usize,
+    /// Position of the cursor in the selected input, in characters
+    pub cursor: usize,
+    pub changed: bool,
+    /// Whether Esc was typed with changes, which another Esc discards
+    pub discarding: bool,
+    /// Why the resume could not be saved
+    pub status: Option<String>,
+}
+
+impl Editor {
+    /// The form of a section, by its index in the tabs, `None` for the Match one.
+    pub fn new(resume: Resume, section: usize) -> Option<Editor> {
+        let inputs = match section {
+            0 => vec![name(&resume)],
+            1 => vec![about(&resume)],
+            6 => vec![
+                Input::line("Headline", &resume.objective.headline, assign(|resume| &mut resume.objective.headline, any)),
+                Input::text("Introduction", &resume.objective.intro, assign(|resume| &mut resume.objective.intro, any)),
+            ],
+            2..=5 => Vec::new(),
+            _ => return None,
+        };
+        Some(Editor::form(resume, inputs, List::of(section)))
+    }
+
+    /// The form of a step of `rust-cv init`, by its index in `STEPS`.
+    pub fn step(resume: Resume, step: usize) -> Editor {
+        let (inputs, lists): (Vec<Input>, &[List]) = match step {
+            0 => (vec![name(&resume), about(&resume)], &[]),
+            1 => (Vec::new(), &[List::Contact]),
+            2 => (Vec::new(), &[List::Languages]),
+            3 => (Vec::new(), &[List::Skills]),
+            _ => (Vec::new(), &[List::Jobs]),
+        };
+        Editor::form(resume, inputs, lists)
+    }
+
+    /// A form with some inputs followed by those of the items of lists.
+    fn form(mut resume: Resume, mut inputs: Vec<Input>, lists: &[List]) -> Editor {
+        for &list in lists {
+            for index in 0..list.len(&mut resume) {
+                inputs.extend(list.inputs(&mut resume, index));
+            }
+        }
+        if inputs.is_empty() {
+            // Nothing to edit yet: start with an item of the main list
+            let list = lists[0];
+            list.push(&mut resume);
+            inputs = list.inputs(&mut resume, 0);
+            for input in &mut inputs {
+                input.value.clear();
+            }
+        }
+        let cursor = inputs[0].value.chars().count();
+        Editor {
+            resume,
+            inputs,
+            selected: 0,
+            cursor,
+            changed: false,
+            discarding: false,
+            status: None,
+        }
+    }
+
+    /// Handles a key, editing the selected input or moving to another one.
+    pub fn key(&mut self, key: Key) -> Action {
+        let discarding = self.discarding;
+        self.discarding = false;
+        let multiline = self.inputs[self.selected].multiline;
+        match key {
+            Key::Esc if self.changed && !discarding => self.discarding = true,
+            Key::Esc => return Action::Cancel,
+            Key::Ctrl('s') => return Action::Save,
+            Key::Ctrl('n') => self.add(),
+            Key::Char('\t') => self.select((self.selected + 1).min(self.inputs.len() - 1)),
+            Key::BackTab => self.select(self.selected.saturating_sub(1)),
+            Key::Char('\n') if !multiline => self.select((self.selected + 1).min(self.inputs.len() - 1)),
+            Key::Up => self.up(),
+            Key::Down => self.down(),
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.chars().len()),
+            Key::Home => self.cursor = self.line_start(),
+            Key::End => {
+                let chars = self.chars();
+                self.cursor += chars[self.cursor..].iter().take_while(|&&c| c != '\n').count();
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.remove();
+            }
+            Key::Delete if self.cursor < self.chars().len() => self.remove(),
+            Key::Char(c) if !c.is_control() || c == '\n' => {
+                let at = self.byte(self.cursor);
+                self.inputs[self.selected].value.insert(at, c);
+                self.cursor += 1;
+                self.changed = true;
+            }
+            _ => {}
+        }
+        Action::Nothing
+    }
+
+    fn chars(&self) -> Vec<char> {
+        self.inputs[self.selected].value.chars().collect()
+    }
+
+    /// Index in the value of the selected input of the character at a position.
+    fn byte(&self, position: usize) -> usize {
+        let value = &self.inputs[self.selected].value;
+        value.char_indices().nth(position).map_or(value.len(), |(at, _)| at)
+    }
+
+    fn remove(&mut self) {
+        let at = self.byte(self.cursor);
+        self.inputs[self.selected].value.remove(at);
+        self.changed = true;
+    }
+
+    fn select(&mut self, input: usize) {
+        self.selected = input;
+        self.cursor = self.chars().len();
+    }
+
+    /// Position of the start of the line the cursor is on.
+    fn line_start(&self) -> usize {
+        self.cursor - self.chars()[..self.cursor].iter().rev().take_while(|&&c| c != '\n').count()
+    }
+
+    /// Moves to the line above, in a text, or else to the previous input.
+    fn up(&mut self) {
+        let start = self.line_start();
+        if start == 0 {
+            self.select(self.selected.saturating_sub(1));
+            return;
+        }
+        let column = self.cursor - start;
+        let chars = self.chars();
+        let above = start - 1 - chars[..start - 1].iter().rev().take_while(|&&c| c != '\n').count();
+        self.cursor = above + column.min(start - 1 - above);
+    }
+
+    /// Moves to the line below, in a text, or else to the next input.
+    fn down(&mut self) {
+        let chars = self.chars();
+        let end = self.cursor + chars[self.cursor..].iter().take_while(|&&c| c != '\n').count();
+        if end == chars.len() {
+            self.select((self.selected + 1).min(self.inputs.len() - 1));
+            return;
+        }
+        let column = self.cursor - self.line_start();
+        let below = end + 1;
+        let length = chars[below..].iter().take_while(|&&c| c != '\n').count();
+        self.cursor = below + column.min(length);
+    }
+
+    /// Adds an item to the list of the selected input, or to the main list of the section,
+    /// selecting its first input.
+    fn add(&mut self) {
+        let list = match self.inputs[self.selected].item.or_else(|| self.inputs.iter().find_map(|input| input.item)) {
+            Some((list, _)) => list,
+            None => return,
+        };
+        let index = list.len(&mut self.resume);
+        list.push(&mut self.resume);
+        let mut inputs = list.inputs(&mut self.resume, index);
+        for input in &mut inputs {
+            input.value.clear();
+        }
+        // After the last item of the list
+        let at = self.inputs.iter().rposition(|input| input.item.is_some_and(|(other, _)| other == list)).map_or(0, |at| at + 1);
+        self.inputs.splice(at..at, inputs);
+        self.select(at);
+        self.changed = true;
+    }
+
+    /// The resume with what was typed, items whose inputs are all empty being removed. `None`
+    /// when something is not valid, the inputs then saying what and the first one being
+    /// selected.
+    pub fn apply(&mut self) -> Option<Resume> {
+        let mut resume = self.resume.clone();
+        let blank = |inputs: &[Input], item| inputs.iter().filter(|input| input.item == Some(item)).all(|input| input.value.trim().is_empty());
+        let mut removed = Vec::new();
+        for i in 0..self.inputs.len() {
+            self.inputs[i].error = None;
+            if let Some(item) = self.inputs[i].item {
+                if blank(&self.inputs, item) {
+                    if !removed.contains(&item) {
+                        removed.push(item);
+                    }
+                    continue;
+                }
+            }
+            let input = &mut self.inputs[i];
+            if let Err(error) = (input.set)(&mut resume, input.value.trim()) {
+                input.error = Some(error);
+            }
+        }
+        if let Some(invalid) = self.inputs.iter().position(|input| input.error.is_some()) {
+            self.select(invalid);
+            return None;
+        }
+        // The last items first, for the others to stay where they are
+        removed.sort_by_key(|&(_, index)| std::cmp::Reverse(index));
+        for (list, index) in removed {
+            list.remove(&mut resume, index);
+        }
+        Some(resume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empties the inputs of an item, as if every character of them was erased.
+    fn empty(editor: &mut Editor, list: List, index: usize) {
+        for input in editor.inputs.iter_mut().filter(|input| input.item == Some((list, index))) {
+            input.value.clear();
+        }
+    }
+
+    fn labels(fields: &[Field]) -> Vec<&str> {
+        fields.iter().map(|field| field.label.as_str()).collect()
+    }
+
+    fn type_text(editor: &mut Editor, text: &str) {
+        for c in text.chars() {
+            editor.key(Key::Char(c));
+        }
+    }
+
+    #[test]
+    fn emptied_items_are_removed() {
+        let resume = Resume::builtin();
+        let mut editor = Editor::new(resume.clone(), 1).unwrap();
+        empty(&mut editor, List::Contact, 0);
+        empty(&mut editor, List::Contact, 2);
+        empty(&mut editor, List::Languages, resume.languages.len() - 1);
+        let applied = editor.apply().unwrap();
+        let mut contact = labels(&resume.contact);
+        contact.remove(2);
+        contact.remove(0);
+        assert_eq!(labels(&applied.contact), contact);
+        assert_eq!(labels(&applied.languages), labels(&resume.languages[..resume.languages.len() - 1]));
+        assert_eq!(labels(&applied.information), labels(&resume.information));
+    }
+
+    #[test]
+    fn added_items_are_kept_once_filled() {
+        let resume = Resume::builtin();
+        let mut editor = Editor::new(resume.clone(), 2).unwrap();
+        editor.key(Key::Ctrl('n'));
+        assert_eq!(editor.inputs[editor.selected].heading, Some(("Skill {}", resume.skills.len() + 1)));
+        // Blank, it is left out
+        assert_eq!(editor.apply().map(|applied| applied.skills.len()), Some(resume.skills.len()));
+        type_text(&mut editor, "Zig");
+        editor.key(Key::Char('\t'));
+        type_text(&mut editor, "30");
+        let applied = editor.apply().unwrap();
+        assert_eq!(applied.skills.len(), resume.skills.len() + 1);
+        assert_eq!((applied.skills[resume.skills.len()].name.as_str(), applied.skills[resume.skills.len()].level), ("Zig", 30));
+    }
+
+    #[test]
+    fn invalid_inputs_are_selected() {
+        let mut editor = Editor::new(Resume::builtin(), 3).unwrap();
+        let end = editor.inputs.iter().position(|input| input.label == "End, empty while it lasts" && !input.value.is_empty()).unwrap();
+        editor.inputs[end].value = String::from("1990");
+        assert!(editor.apply().is_none());
+        assert_eq!(editor.selected, end);
+        assert_eq!(editor.inputs[end].error, Some(String::from("Ends before it starts")));
+    }
+}
@@ -0,0 +1,543 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
+
+use flate2::read::ZlibDecoder;
+
+/// Kinds of objects in a pack, from the type bits of an entry.
+const COMMIT: u8 = 1;
+const TREE: u8 = 2;
+const BLOB: u8 = 3;
+const TAG: u8 = 4;
+/// A delta against an object earlier in the same pack, or against any object by its id.
+const OFS_DELTA: u8 = 6;
+const REF_DELTA: u8 = 7;
+
+/// The SHA-1 an object is stored by.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Oid([u8; 20]);
+
+impl Oid {
+    fn parse(hex: &str) -> Option<Oid> {
+        let hex = hex.trim();
+        if hex.len() != 40 || !hex.is_ascii() {
+            return None;
+        }
+        let mut bytes = [0; 20];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+        }
+        Some(Oid(bytes))
+    }
+
+    fn from_slice(bytes: &[u8]) -> Oid {
+        let mut oid = [0; 20];
+        oid.copy_from_slice(&bytes[..20]);
+        Oid(oid)
+    }
+
+    pub fn hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// The first seven digits, as `git log --oneline` writes it.
+    pub fn short(&self) -> String {
+        self.hex()[..7].to_string()
+    }
+}
+
+/// What a commit says, without its message past the first line.
+#[derive(Clone)]
+pub struct Commit {
+    pub id: Oid,
+    tree: Oid,
+    parents: Vec<Oid>,
+    pub author: String,
+    /// Seconds since 1970 when it was committed, and the offset of its time zone in minutes
+    pub time: i64,
+    pub offset: i64,
+    pub summary: String,
+}
+
+impl Commit {
+    fn parse(id: Oid, data: &[u8]) -> Result<Commit, String> {
+        let text = String::from_utf8_lossy(data);
+        let (headers, message) = match text.find("\n\n") {
+            Some(end) => (&text[..end], &text[end + 2..]),
+            None => (&text[..], ""),
+        };
+        let mut commit = Commit {
+            id,
+            tree: Oid([0; 20]),
+            parents: Vec::new(),
+            author: String::new(),
+            time: 0,
+            offset: 0,
+            summary: String::from(message.lines().next().unwrap_or("").trim()),
+        };
+        let mut tree = None;
+        for line in headers.lines() {
+            if let Some(hex) = line.strip_prefix("tree ") {
+                tree = Oid::parse(hex);
+            } else if let Some(hex) = line.strip_prefix("parent ") {
+                commit.parents.extend(Oid::parse(hex));
+            } else if let Some(author) = line.strip_prefix("author ") {
+                commit.author = String::from(author.split(" <").next().unwrap_or(author));
+            } else if let Some(committer) = line.strip_prefix("committer ") {
+                // "Name <email> 1700000000 +0100"
+                let mut fields = committer.rsplit(' ');
+                let zone = fields.next().unwrap_or("+0000");
+                commit.time = fields.next().and_then(|time| time.parse().ok()).unwrap_or(0);
+                let minutes = zone.get(1..3).and_then(|hours| hours.parse::<i64>().ok()).unwrap_or(0) * 60
+                    + zone.get(3..5).and_then(|minutes| minutes.parse::<i64>().ok()).unwrap_or(0);
+                commit.offset = if zone.starts_with('-') { -minutes } else { minutes };
+            }
+        }
+        commit.tree = tree.ok_or_else(|| format!("Commit {} has no tree", id.hex()))?;
+        Ok(commit)
+    }
+}
+
+/// The index of a pack file, where its objects are found by their id.
+struct Pack {
+    path: PathBuf,
+    index: Vec<u8>,
+}
+
+impl Pack {
+    fn open(index: &Path) -> Result<Pack, String> {
+        let data = fs::read(index).map_err(|error| format!("Cannot read {}: {}", index.display(), error))?;
+        // Version 2: a magic number and the version, then a fan-out table of 256 counts
+        if data.len() < 8 + 256 * 4 || data[..8] != [0xff, b't', b'O', b'c', 0, 0, 0, 2] {
+            return Err(format!("Unsupported pack index {}, only version 2 is read", index.display()));
+        }
+        Ok(Pack { path: index.with_extension("pack"), index: data })
+    }
+
+    fn word(&self, at: usize) -> u32 {
+        let bytes = &self.index[at..at + 4];
+        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    /// Where the object starts in the pack, if the pack has it.
+    fn offset(&self, oid: Oid) -> Option<u64> {
+        let fan_out = |byte: usize| self.word(8 + byte * 4) as usize;
+        let count = fan_out(255);
+        let (mut low, mut high) = (if oid.0[0] == 0 { 0 } else { fan_out(oid.0[0] as usize - 1) }, fan_out(oid.0[0] as usize));
+        let names = 8 + 256 * 4;
+        while low < high {
+            let middle = (low + high) / 2;
+            let name = &self.index[names + middle * 20..names + middle * 20 + 20];
+            match name.cmp(&oid.0[..]) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => {
+                    // Past the names and their checksums, with the large offsets after them
+                    let offsets = names + count * 24;
+                    let offset = self.word(offsets + middle * 4);
+                    if offset & 0x8000_0000 == 0 {
+                        return Some(u64::from(offset));
+                    }
+                    let large = offsets + count * 4 + (offset & 0x7fff_ffff) as usize * 8;
+                    return Some(u64::from(self.word(large)) << 32 | u64::from(self.word(large + 4)));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// A git repository read with nothing but the standard library and a zlib decoder, to find the
+/// versions a file had.
+pub struct Repository {
+    /// The `.git` directory, and the one with the objects and branches, different for a worktree
+    git_dir: PathBuf,
+    common_dir: PathBuf,
+    packs: Vec<Pack>,
+    /// Commits whose parents were left out of a shallow clone
+    shallow: HashSet<Oid>,
+}
+
+impl Repository {
+    /// The repository a file is in, looking up from its directory, and the path of the file in it.
+    pub fn discover(path: &Path) -> Result<(Repository, String), String> {
+        let path = fs::canonicalize(path).map_err(|error| format!("Cannot read {}: {}", path.display(), error))?;
+        let work_dir = path.ancestors().skip(1)
+            .find(|dir| dir.join(".git").exists())
+            .ok_or_else(|| format!("{} is not in a git repository", path.display()))?;
+        let dot_git = work_dir.join(".git");
+        // A worktree or a submodule has a file pointing at the real directory
+        let git_dir = if dot_git.is_file() {
+            let text = fs::read_to_string(&dot_git).map_err(|error| format!("Cannot read {}: {}", dot_git.display(), error))?;
+            let target = text.trim().strip_prefix("gitdir: ").ok_or_else(|| format!("Unknown format of {}", dot_git.display()))?;
+            work_dir.join(target)
+        } else {
+            dot_git
+        };
+        let common_dir = match fs::read_to_string(git_dir.join("commondir")) {
+            Ok(common) => git_dir.join(common.trim()),
+            Err(_) => git_dir.clone(),
+        };
+        let mut packs = Vec::new();
+        if let Ok(entries) = fs::read_dir(common_dir.join("objects").join("pack")) {
+            for entry in entries.filter_map(Result::ok) {
+                if entry.path().extension().is_some_and(|extension| extension == "idx") {
+                    packs.push(Pack::open(&entry.path())?);
+                }
+            }
+        }
+        let shallow = fs::read_to_string(common_dir.join("shallow"))
+            .map(|text| text.lines().filter_map(Oid::parse).collect())
+            .unwrap_or_default();
+        let relative = path.strip_prefix(work_dir).unwrap_or(&path).components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+        let repository = Repository {
+            git_dir,
+            common_dir,
+            packs,
+            shallow,
+        };
+        Ok((repository, relative))
+    }
+
+    /// The commit checked out, following the branch it is on.
+    fn head(&self) -> Result<Oid, String> {
+        let mut reference = String::from("HEAD");
+        // A branch may point to another one, but not forever
+        for _ in 0..10 {
+            let text = [&self.git_dir, &self.common_dir].iter()
+                .find_map(|dir| fs::read_to_string(dir.join(&reference)).ok())
+                .or_else(|| self.packed_ref(&reference));
+            let text = match text {
+                Some(text) => text,
+                None => return Err(format!("No commits on {}", reference.trim_start_matches("refs/heads/"))),
+            };
+            match text.trim().strip_prefix("ref: ") {
+                Some(target) => reference = String::from(target),
+                None => return Oid::parse(&text).ok_or_else(|| format!("Unknown format of {}", reference)),
+            }
+        }
+        Err(String::from("Too many symbolic references from HEAD"))
+    }
+
+    /// A branch from the `packed-refs` file, where `git gc` moves them.
+    fn packed_ref(&self, name: &str) -> Option<String> {
+        let text = fs::read_to_string(self.common_dir.join("packed-refs")).ok()?;
+        text.lines()
+            .filter(|line| !line.starts_with('#') && !line.starts_with('^'))
+            .find(|line| line.split(' ').nth(1) == Some(name))
+            .map(|line| String::from(line.split(' ').next().unwrap_or("")))
+    }
+
+    /// The kind and contents of an object, whether loose or packed.
+    fn object(&self, oid: Oid) -> Result<(u8, Vec<u8>), String> {
+        let hex = oid.hex();
+        let loose = self.common_dir.join("objects").join(&hex[..2]).join(&hex[2..]);
+        if let Ok(file) = File::open(&loose) {
+            let mut data = Vec::new();
+            ZlibDecoder::new(file).read_to_end(&mut data).map_err(|error| format!("Cannot read object {}: {}", hex, error))?;
+            // "blob 1234\0" before the contents
+            let nul = data.iter().position(|&byte| byte == 0).ok_or_else(|| format!("Corrupt object {}", hex))?;
+            let kind = match data[..nul].split(|&byte| byte == b' ').next() {
+                Some(b"commit") => COMMIT,
+                Some(b"tree") => TREE,
+                Some(b"blob") => BLOB,
+                Some(b"tag") => TAG,
+                _ => return Err(format!("Corrupt object {}", hex)),
+            };
+            return Ok((kind, data.split_off(nul + 1)));
+        }
+        for pack in &self.packs {
+            if let Some(offset) = pack.offset(oid) {
+                return self.packed(pack, offset).map_err(|error| format!("Cannot read object {}: {}", hex, error));
+            }
+        }
+        Err(format!("Object {} not found", hex))
+    }
+
+    /// The object at an offset of a pack, applying the deltas it is stored as.
+    fn packed(&self, pack: &Pack, offset: u64) -> Result<(u8, Vec<u8>), String> {
+        let file = File::open(&pack.path).map_err(|error| error.to_string())?;
+        let mut file = BufReader::new(file);
+        file.seek(SeekFrom::Start(offset)).map_err(|error| error.to_string())?;
+        let mut byte = || -> Result<u8, String> {
+            let mut byte = [0];
+            file.read_exact(&mut byte).map_err(|error| error.to_string())?;
+            Ok(byte[0])
+        };
+        // The kind and the size, seven bits at a time past the first four bits of the size
+        let mut current = byte()?;
+        let kind = (current >> 4) & 7;
+        let mut size = u64::from(current & 0x0f);
+        let mut shift = 4;
+        while current & 0x80 != 0 {
+            current = byte()?;
+            size |= u64::from(current & 0x7f) << shift;
+            shift += 7;
+        }
+        let base = match kind {
+            OFS_DELTA => {
+                // How far back the base starts, with one added for every byte past the first
+                current = byte()?;
+                let mut distance = u64::from(current & 0x7f);
+                while current & 0x80 != 0 {
+                    current = byte()?;
+                    distance = ((distance + 1) << 7) | u64::from(current & 0x7f);
+                }
+                Some(self.packed(pack, offset.checked_sub(distance).ok_or("Corrupt delta")?)?)
+            }
+            REF_DELTA => {
+                let mut id = [0; 20];
+                for byte_of_id in id.iter_mut() {
+                    *byte_of_id = byte()?;
+                }
+                Some(self.object(Oid(id))?)
+            }
+            COMMIT | TREE | BLOB | TAG => None,
+            _ => return Err(format!("Unknown object type {}", kind)),
+        };
+        let mut data = Vec::new();
+        ZlibDecoder::new(file).take(size).read_to_end(&mut data).map_err(|error| error.to_string())?;
+        match base {
+            Some((kind, base)) => Ok((kind, apply(&base, &data)?)),
+            None => Ok((kind, data)),
+        }
+    }
+
+    fn commit(&self, oid: Oid) -> Result<Commit, String> {
+        match self.object(oid)? {
+            (COMMIT, data) => Commit::parse(oid, &data),
+            _ => Err(format!("{} is not a commit", oid.hex())),
+        }
+    }
+
+    /// The blob at a path of the tree of a commit, if the path was there.
+    fn file(&self, commit: &Commit, path: &str) -> Result<Option<Oid>, String> {
+        let mut oid = commit.tree;
+        for name in path.split('/') {
+            let data = match self.object(oid)? {
+                (TREE, data) => data,
+                _ => return Ok(None),
+            };
+            // Entries are "100644 name\0" followed by the id of the blob or tree
+            let mut found = None;
+            let mut rest = &data[..];
+            while let Some(nul) = rest.iter().position(|&byte| byte == 0) {
+                if rest.len() < nul + 21 {
+                    break;
+                }
+                let entry = &rest[..nul];
+                let entry_name = entry.splitn(2, |&byte| byte == b' ').nth(1).unwrap_or(b"");
+                if entry_name == name.as_bytes() {
+                    found = Some(Oid::from_slice(&rest[nul + 1..]));
+                    break;
+                }
+                rest = &rest[nul + 21..];
+            }
+            oid = match found {
+                Some(oid) => oid,
+                None => return Ok(None),
+            };
+        }
+        Ok(Some(oid))
+    }
+
+    /// The text of a blob.
+    pub fn blob(&self, oid: Oid) -> Result<String, String> {
+        match self.object(oid)? {
+            (BLOB, data) => String::from_utf8(data).map_err(|_| format!("{} is not text", oid.hex())),
+            _ => Err(format!("{} is not a file", oid.hex())),
+        }
+    }
+
+    /// The commits reachable from HEAD that changed the file at a path, the most recent first,
+    /// with the blob it had after each and the one it had before, if any. Merges that took the
+    /// file as it was on one side are left out, as are commits where the file was removed.
+    pub fn log(&self, path: &str) -> Result<Vec<(Commit, Oid, Option<Oid>)>, String> {
+        let mut log = Vec::new();
+        let mut seen = HashSet::new();
+        let mut commits: HashMap<Oid, Commit> = HashMap::new();
+        // Newest first, by the time they were committed
+        let mut queue = BinaryHeap::new();
+        let head = self.commit(self.head()?)?;
+        queue.push((head.time, head.id));
+        seen.insert(head.id);
+        commits.insert(head.id, head);
+        while let Some((_, oid)) = queue.pop() {
+            let commit = match commits.remove(&oid) {
+                Some(commit) => commit,
+                None => continue,
+            };
+            let blob = self.file(&commit, path)?;
+            let mut changed = blob.is_some();
+            let mut before = None;
+            let parents = if self.shallow.contains(&commit.id) { Vec::new() } else { commit.parents.clone() };
+            for parent in parents {
+                let parent = match commits.get(&parent) {
+                    Some(parent) => parent.clone(),
+                    None => self.commit(parent)?,
+                };
+                let parent_blob = self.file(&parent, path)?;
+                if parent_blob == blob {
+                    changed = false;
+                }
+                before = before.or(parent_blob);
+                if seen.insert(parent.id) {
+                    queue.push((parent.time, parent.id));
+                    commits.insert(parent.id, parent);
+                }
+            }
+            if let (true, Some(blob)) = (changed, blob) {
+                log.push((commit, blob, before));
+            }
+        }
+        Ok(log)
+    }
+}
+
+/// Reads a size of a delta, seven bits at a time.
+fn delta_size(data: &[u8], at: &mut usize) -> Result<usize, String> {
+    let mut size = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *data.get(*at).ok_or("Corrupt delta")?;
+        *at += 1;
+        size |= ((byte & 0x7f) as usize) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            return Ok(size);
+        }
+    }
+}
+
+/// The object a delta makes of its base: ranges copied from the base and bytes inserted.
+fn apply(base: &[u8], delta: &[u8]) -> Result<Vec<u8>, String> {
+    let mut at = 0;
+    if delta_size(delta, &mut at)? != base.len() {
+        return Err(String::from("Delta for another base"));
+    }
+    let size = delta_size(delta, &mut at)?;
+    let mut data = Vec::with_capacity(size);
+    while at < delta.len() {
+        let instruction = delta[at];
+        at += 1;
+        if instruction & 0x80 != 0 {
+            // Which bytes of the offset and the size follow, the others being zero
+            let mut fields = [0usize; 7];
+            for (bit, field) in fields.iter_mut().enumerate() {
+                if instruction & (1 << bit) != 0 {
+                    *field = *delta.get(at).ok_or("Corrupt delta")? as usize;
+                    at += 1;
+                }
+            }
+            let offset = fields[0] | fields[1] << 8 | fields[2] << 16 | fields[3] << 24;
+            let length = match fields[4] | fields[5] << 8 | fields[6] << 16 {
+                0 => 0x10000,
+                length => length,
+            };
+            data.extend_from_slice(base.get(offset..offset + length).ok_or("Corrupt delta")?);
+        } else if instruction != 0 {
+            let length = instruction as usize;
+            data.extend_from_slice(delta.get(at..at + length).ok_or("Corrupt delta")?);
+            at += length;
+        } else {
+            return Err(String::from("Corrupt delta"));
+        }
+    }
+    if data.len() != size {
+        return Err(String::from("Corrupt delta"));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process::{self, Command};
+
+    use super::*;
+
+    #[test]
+    fn deltas_copy_from_their_base_and_insert() {
+        let base = b"the quick brown fox";
+        // Sizes of the base and the result, copy 4 bytes at 0, insert "slow", copy 10 bytes
+        // at 9, insert "!"
+        let delta = b"\x13\x13\x90\x04\x04slow\x91\x09\x0a\x01!";
+        assert_eq!(apply(base, delta), Ok(b"the slow brown fox!".to_vec()));
+        assert_eq!(apply(b"another base", delta), Err(String::from("Delta for another base")));
+        // Copying past the end of the base
+        assert_eq!(apply(base, b"\x13\x14\x91\x09\x14"), Err(String::from("Corrupt delta")));
+        // A result shorter than announced
+        assert_eq!(apply(base, b"\x13\x05\x01!"), Err(String::from("Corrupt delta")));
+    }
+
+    #[test]
+    fn sizes_take_seven_bits_a_byte() {
+        let mut at = 0;
+        assert_eq!(delta_size(b"\x91\x2e\x05", &mut at), Ok(0x1711));
+        assert_eq!(at, 2);
+        assert!(delta_size(b"\x80", &mut 0).is_err());
+    }
+
+    /// Commits each version of a file in a new repository, at a minute from each other.
+    fn repository(name: &str, versions: &[&str]) -> Option<PathBuf> {
+        let dir = env::temp_dir().join(format!("rust-cv-git-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).ok()?;
+        let git = |args: &[&str], time: usize| {
+            Command::new("git")
+                .args(["-c", "user.name=Daisy T", "-c", "user.email=daisy@example.com", "-c", "commit.gpgsign=false"])
+                .args(args)
+                .current_dir(&dir)
+                .env("GIT_AUTHOR_DATE", format!("{} +0100", 1_600_000_000 + time * 60))
+                .env("GIT_COMMITTER_DATE", format!("{} +0100", 1_600_000_000 + time * 60))
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+        };
+        // Without git there is nothing to read
+        if !git(&["init", "-q"], 0) {
+            return None;
+        }
+        for (i, version) in versions.iter().enumerate() {
+            fs::write(dir.join("resume.toml"), version).ok()?;
+            assert!(git(&["add", "resume.toml"], i) && git(&["commit", "-q", "-m", &format!("Version {}", i + 1)], i));
+        }
+        Some(dir)
+    }
+
+    fn versions(dir: &Path) -> Vec<(String, String, bool)> {
+        let (repository, path) = Repository::discover(&dir.join("resume.toml")).unwrap();
+        assert_eq!(path, "resume.toml");
+        repository.log(&path).unwrap().into_iter()
+            .map(|(commit, blob, before)| (commit.summary, repository.blob(blob).unwrap(), before.is_some()))
+            .collect()
+    }
+
+    #[test]
+    fn versions_are_read_from_loose_and_packed_objects() {
+        let text = (0..200).map(|i| format!("line = {}\n", i)).collect::<String>();
+        let edited = text.replace("line = 100\n", "line = one hundred\n");
+        let dir = match repository("versions", &[&text, &edited]) {
+            Some(dir) => dir,
+            None => return,
+        };
+        let expected = vec![
+            (String::from("Version 2"), edited.clone(), true),
+            (String::from("Version 1"), text.clone(), false),
+        ];
+        assert_eq!(versions(&dir), expected);
+        // Packed, one version of the file being stored as a delta of the other
+        let packed = Command::new("git").args(["gc", "-q", "--aggressive"]).current_dir(&dir).status();
+        assert!(packed.is_ok_and(|status| status.success()));
+        assert!(dir.join(".git/objects/pack").read_dir().unwrap().next().is_some());
+        assert_eq!(versions(&dir), expected);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+use std::path::Path;
+
+use data;
+use date::Date;
+use git::{Commit, Repository};
+use resume::Resume;
+
+/// A resume file as it was after a commit, or why it could not be read then.
+pub struct Version {
+    pub commit: Commit,
+    pub resume: Result<Resume, String>,
+    /// The file in the parent commit, if it was there
+    before: Option<Result<Resume, String>>,
+}
+
+impl Version {
+    /// When it was committed, in the time zone of the committer, and the day of the month.
+    pub fn date(&self) -> (Date, u32) {
+        Date::from_timestamp(self.commit.time + self.commit.offset * 60)
+    }
+}
+
+/// The versions of a resume file in its git repository, the list they are picked from and
+/// whose resume is shown.
+pub struct History {
+    /// Where the file is in the repository
+    pub file: String,
+    /// The file as it is now, uncommitted changes included
+    pub current: Resume,
+    /// The most recent first
+    pub versions: Vec<Version>,
+    /// Position of the selected version in the list
+    pub selected: usize,
+    /// Whether the resume of the selected version is shown instead of the list
+    pub open: bool,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<History, String> {
+        let current = data::load(path)?;
+        let (repository, file) = Repository::discover(path)?;
+        let versions = repository.log(&file)?.into_iter()
+            .map(|(commit, blob, before)| {
+                let source = format!("{} at {}", file, commit.id.short());
+                let read = |blob| repository.blob(blob).and_then(|text| data::parse(&text, &source));
+                let (resume, before) = (read(blob), before.map(read));
+                Version { commit, resume, before }
+            })
+            .collect::<Vec<Version>>();
+        if versions.is_empty() {
+            return Err(format!("{} was never committed", file));
+        }
+        Ok(History {
+            file,
+            current,
+            versions,
+            selected: 0,
+            open: false,
+        })
+    }
+
+    pub fn selection(&self) -> &Version {
+        &self.versions[self.selected]
+    }
+
+    /// The resume the selected version was changed from: the file in the parent commit, or
+    /// when it could not be read, the version before it in the list that could.
+    pub fn previous_version(&self) -> Option<&Resume> {
+        match self.selection().before {
+            Some(Ok(ref resume)) => Some(resume),
+            Some(Err(_)) => self.versions[self.selected + 1..].iter().find_map(|version| version.resume.as_ref().ok()),
+            None => None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.versions.len();
+    }
+
+    pub fn previous(&mut self) {
+        let count = self.versions.len();
+        self.selected = (self.selected + count - 1) % count;
+    }
+}
@@ -0,0 +1,253 @@
+/// Languages the interface is available in. The text of the application is written in English,
+/// which is also what is shown when a text has no translation.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum Lang {
+    #[default]
+    English,
+    German,
+}
+
+/// Interface text in German, from the English text.
+const GERMAN: &[(&str, &str)] = &[
+    // Sections
+    ("Sections", "Abschnitte"),
+    ("Home", "Start"),
+    ("About", "Über mich"),
+    ("Skills", "Kenntnisse"),
+    ("Experience", "Berufserfahrung"),
+    ("Education", "Ausbildung"),
+    ("Projects", "Projekte"),
+    ("Objective", "Ziele"),
+    ("Match", "Abgleich"),
+    // Home
+    ("{}'S RESUME", "LEBENSLAUF VON {}"),
+    ("Use {mod=bold;fg=yellow ←}  and {mod=bold;fg=yellow →}  to navigate between the sections.",
+     "Mit {mod=bold;fg=yellow ←}  und {mod=bold;fg=yellow →}  wechseln Sie zwischen den Abschnitten."),
+    ("Type {mod=bold;fg=yellow l} to switch the language.",
+     "Mit {mod=bold;fg=yellow l} wechseln Sie die Sprache."),
+    ("Type {mod=bold;fg=yellow l} to switch the language, {mod=bold;fg=yellow e} to edit a section.",
+     "Mit {mod=bold;fg=yellow l} wechseln Sie die Sprache, mit {mod=bold;fg=yellow e} bearbeiten Sie einen Abschnitt."),
+    ("Type {mod=bold;fg=yellow q} to exit the application.",
+     "Mit {mod=bold;fg=yellow q} beenden Sie die Anwendung."),
+    ("\n{mod=bold;fg=yellow Terminal too small}\n\nPlease resize it to\nat least {}x{}\n(currently {}x{}).\n\n\
+      Type {mod=bold;fg=yellow q} to exit.",
+     "\n{mod=bold;fg=yellow Terminal zu klein}\n\nBitte vergrößern Sie es\nauf mindestens {}x{}\n(derzeit {}x{}).\n\n\
+      Mit {mod=bold;fg=yellow q} beenden."),
+    ("Type {mod=bold;fg=yellow Esc} to go back to the people, {mod=bold;fg=yellow q} to exit.",
+     "Mit {mod=bold;fg=yellow Esc} kehren Sie zu den Personen zurück, mit {mod=bold;fg=yellow q} beenden Sie die Anwendung."),
+    ("Type {mod=bold;fg=yellow Esc} to go back to the versions, {mod=bold;fg=yellow q} to exit.",
+     "Mit {mod=bold;fg=yellow Esc} kehren Sie zu den Versionen zurück, mit {mod=bold;fg=yellow q} beenden Sie die Anwendung."),
+    // Team
+    ("People ({} of {})", "Personen ({} von {})"),
+    ("{mod=bold;fg=yellow /} search, {mod=bold;fg=yellow ↑↓} select, {mod=bold;fg=yellow Enter} open, \
+      {mod=bold;fg=yellow c} compare, {mod=bold;fg=yellow q} exit",
+     "{mod=bold;fg=yellow /} suchen, {mod=bold;fg=yellow ↑↓} auswählen, {mod=bold;fg=yellow Enter} öffnen, \
+      {mod=bold;fg=yellow c} vergleichen, {mod=bold;fg=yellow q} beenden"),
+    ("{mod=bold;fg=yellow c} compares with {}, {mod=bold;fg=yellow Esc} cancels",
+     "{mod=bold;fg=yellow c} vergleicht mit {}, {mod=bold;fg=yellow Esc} bricht ab"),
+    // History
+    ("History of {}", "Verlauf von {}"),
+    ("{mod=bold;fg=yellow ↑↓} select, {mod=bold;fg=yellow Enter} open, \
+      {mod=bold;fg=yellow c} compare with now, {mod=bold;fg=yellow q} exit",
+     "{mod=bold;fg=yellow ↑↓} auswählen, {mod=bold;fg=yellow Enter} öffnen, \
+      {mod=bold;fg=yellow c} mit jetzt vergleichen, {mod=bold;fg=yellow q} beenden"),
+    ("Date", "Datum"),
+    ("Commit", "Commit"),
+    ("Message", "Nachricht"),
+    ("Changes", "Änderungen"),
+    ("First version", "Erste Version"),
+    ("Now", "Jetzt"),
+    // Comparison
+    ("Compare - ←→ section, d changes", "Vergleich - ←→ Abschnitt, d Änderungen"),
+    ("Compare - ←→ section, d changes, Esc back", "Vergleich - ←→ Abschnitt, d Änderungen, Esc zurück"),
+    ("Changes - ↑↓ scroll, d close", "Änderungen - ↑↓ blättern, d schließen"),
+    ("No changes", "Keine Änderungen"),
+    ("not listed", "nicht aufgeführt"),
+    ("No such job", "Keine solche Stelle"),
+    ("No jobs", "Keine Stellen"),
+    ("{mod=bold;fg=yellow Search:} {}{} - {mod=bold;fg=yellow Esc} clear", "{mod=bold;fg=yellow Suche:} {}{} - {mod=bold;fg=yellow Esc} leeren"),
+    ("Role", "Rolle"),
+    ("Top skills", "Stärkste Kenntnisse"),
+    // About
+    ("Information", "Angaben zur Person"),
+    ("Languages", "Sprachen"),
+    ("Contact", "Kontakt"),
+    ("Contact - ↑↓ select, y copy", "Kontakt - ↑↓ auswählen, y kopieren"),
+    ("Contact - {} copied to the clipboard", "Kontakt - {} in die Zwischenablage kopiert"),
+    ("About me", "Über mich"),
+    // Skills
+    ("Programming Languages ({}, {}) - v view, s sort", "Programmiersprachen ({}, {}) - v Ansicht, s Sortierung"),
+    ("gauges", "Anzeigen"),
+    ("bar chart", "Balkendiagramm"),
+    ("radar chart", "Netzdiagramm"),
+    ("matrix", "Matrix"),
+    ("as listed", "wie aufgeführt"),
+    ("by level", "nach Niveau"),
+    ("by name", "nach Name"),
+    ("by years of use", "nach Jahren der Nutzung"),
+    ("by last use", "nach letzter Nutzung"),
+    ("{} (learning)", "{} (lernend)"),
+    ("Learning", "Lernend"),
+    ("Intermediate", "Mittel"),
+    ("Advanced", "Fortgeschritten"),
+    ("Expert", "Experte"),
+    ("Others", "Weitere"),
+    ("{}, still in use", "{}, weiterhin in Gebrauch"),
+    ("{}, last used in {}", "{}, zuletzt {} genutzt"),
+    ("no dated use", "keine datierte Nutzung"),
+    ("Professional experience", "Berufserfahrung insgesamt"),
+    // Experience, education and projects
+    ("Timeline - ↑↓ select", "Zeitleiste - ↑↓ auswählen"),
+    ("Continuing Education", "Weiterbildung"),
+    ("Personal Projects", "Eigene Projekte"),
+    ("Volunteer Work", "Ehrenamt"),
+    ("Open-Source Contributions", "Open-Source-Beiträge"),
+    // Objective
+    ("What I am looking for?", "Was ich suche"),
+    // Match
+    ("The resume has {} of the keywords of the job description.", "Der Lebenslauf hat {} der Schlüsselwörter der Stellenbeschreibung."),
+    ("{} of the keywords of the job description", "{} der Schlüsselwörter der Stellenbeschreibung"),
+    ("Job description", "Stellenbeschreibung"),
+    ("Matching skills", "Passende Kenntnisse"),
+    ("Matching technologies", "Passende Technologien"),
+    ("Keywords found in the resume", "Im Lebenslauf gefundene Schlüsselwörter"),
+    ("Missing keywords", "Fehlende Schlüsselwörter"),
+    ("Most relevant roles:", "Relevanteste Stellen:"),
+    ("{}. {}, {} (relevance {})", "{}. {}, {} (Relevanz {})"),
+    ("none", "keine"),
+    // Editor
+    ("Edit {}", "{} bearbeiten"),
+    ("Tab next, Enter new line, Ctrl+N add an item, Ctrl+S save, Esc cancel",
+     "Tab weiter, Enter neue Zeile, Strg+N Eintrag hinzufügen, Strg+S speichern, Esc abbrechen"),
+    ("Discard the changes? Esc again to discard, any other key to go on editing",
+     "Änderungen verwerfen? Esc verwirft sie, jede andere Taste bearbeitet weiter"),
+    ("Items whose fields are all emptied are removed when saving.",
+     "Einträge, deren Felder alle geleert werden, werden beim Speichern entfernt."),
+    ("Saved to {}", "Gespeichert in {}"),
+    ("Start with --file FILE to edit the resume", "Starten Sie mit --file DATEI, um den Lebenslauf zu bearbeiten"),
+    ("Only the resume given with --file can be edited", "Nur der mit --file angegebene Lebenslauf kann bearbeitet werden"),
+    ("The resume cannot be edited with --variant, which leaves parts of it out",
+     "Mit --variant, das Teile weglässt, kann der Lebenslauf nicht bearbeitet werden"),
+    ("This section cannot be edited", "Dieser Abschnitt kann nicht bearbeitet werden"),
+    ("Headline", "Überschrift"),
+    ("Introduction", "Einleitung"),
+    ("Label", "Bezeichnung"),
+    ("Value", "Wert"),
+    ("Title", "Titel"),
+    ("Start", "Beginn"),
+    ("End, empty while it lasts", "Ende, leer solange es andauert"),
+    ("Description", "Beschreibung"),
+    ("Level, from 0 to 100", "Niveau, von 0 bis 100"),
+    ("Category", "Kategorie"),
+    ("Company", "Unternehmen"),
+    ("Location", "Ort"),
+    ("Goal", "Ziel"),
+    ("Information {}", "Angabe {}"),
+    ("Language {}", "Sprache {}"),
+    ("Contact {}", "Kontakt {}"),
+    ("Skill {}", "Kenntnis {}"),
+    ("Job {}", "Stelle {}"),
+    ("Education {}", "Ausbildung {}"),
+    ("Course {}", "Kurs {}"),
+    ("Project {}", "Projekt {}"),
+    ("Volunteer work {}", "Ehrenamt {}"),
+    ("Contribution {}", "Beitrag {}"),
+    ("Goal {}", "Ziel {}"),
+    ("Language", "Sprache"),
+    ("Level", "Niveau"),
+    // New resume
+    ("New resume {}", "Neuer Lebenslauf {}"),
+    ("Step {} of {}: {}", "Schritt {} von {}: {}"),
+    ("Jobs", "Stellen"),
+    ("Tab next, Enter new line, Ctrl+S next step, Esc quit",
+     "Tab weiter, Enter neue Zeile, Strg+S nächster Schritt, Esc beenden"),
+    ("Tab next, Enter new line, Ctrl+N add an item, Ctrl+S next step, Esc back",
+     "Tab weiter, Enter neue Zeile, Strg+N Eintrag hinzufügen, Strg+S nächster Schritt, Esc zurück"),
+    ("Tab next, Enter new line, Ctrl+N add an item, Ctrl+S write the file, Esc back",
+     "Tab weiter, Enter neue Zeile, Strg+N Eintrag hinzufügen, Strg+S Datei schreiben, Esc zurück"),
+    // Cover letter
+    ("{} and {}", "{} und {}"),
+    ("{} at {} ({}, {}): {}", "{} bei {} ({}, {}): {}"),
+    // Linear mode
+    ("{}'s resume", "Lebenslauf von {}"),
+    ("{} out of 100, {}", "{} von 100, {}"),
+    ("Type a command and press Enter:
+  Enter or n   next item
+  p            previous item
+  r            repeat the current item
+  a            read the rest of the section
+  s            next section
+  b            previous section
+  1 to 7       go to that section
+  l            list the sections
+  h            this help
+  q            quit",
+     "Geben Sie einen Befehl ein und drücken Sie Enter:
+  Enter oder n nächster Eintrag
+  p            vorheriger Eintrag
+  r            aktuellen Eintrag wiederholen
+  a            den Rest des Abschnitts lesen
+  s            nächster Abschnitt
+  b            vorheriger Abschnitt
+  1 bis 7      zu diesem Abschnitt gehen
+  l            die Abschnitte auflisten
+  h            diese Hilfe
+  q            beenden"),
+    ("Section {} of {}: {}, {} item.", "Abschnitt {} von {}: {}, {} Eintrag."),
+    ("Section {} of {}: {}, {} items.", "Abschnitt {} von {}: {}, {} Einträge."),
+    ("Item {} of {}: {}", "Eintrag {} von {}: {}"),
+    ("No items in section {}.", "Keine Einträge im Abschnitt {}."),
+    ("End of section {}. Type s for the next section.", "Ende des Abschnitts {}. Mit s geht es zum nächsten Abschnitt."),
+    ("Start of section {}. Type b for the previous section.", "Anfang des Abschnitts {}. Mit b geht es zum vorherigen Abschnitt."),
+    ("End of section {}.", "Ende des Abschnitts {}."),
+    ("Unknown command {}. Type h for help.", "Unbekannter Befehl {}. Mit h gibt es Hilfe."),
+    ("Command (h for help): ", "Befehl (h für Hilfe): "),
+];
+
+impl Lang {
+    pub fn parse(code: &str) -> Option<Lang> {
+        match code {
+            "en" => Some(Lang::English),
+            "de" => Some(Lang::German),
+            _ => None,
+        }
+    }
+
+    /// ISO 639-1 code, as used for the translations of the resume.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::English => "en",
+            Lang::German => "de",
+        }
+    }
+
+    pub fn next(self) -> Lang {
+        match self {
+            Lang::English => Lang::German,
+            Lang::German => Lang::English,
+        }
+    }
+
+    /// The English text of the interface in this language, or as it is without a translation.
+    pub fn text(self, english: &'static str) -> &'static str {
+        let translations = match self {
+            Lang::English => return english,
+            Lang::German => GERMAN,
+        };
+        translations.iter()
+            .find(|&&(text, _)| text == english)
+            .map_or(english, |&(_, translation)| translation)
+    }
+
+    /// Translates a text with `{}` placeholders, then puts the arguments in their place.
+    pub fn format(self, english: &'static str, arguments: &[&dyn ToString]) -> String {
+        let mut parts = self.text(english).split("{}");
+        let mut arguments = arguments.iter().map(|argument| argument.to_string());
+        let mut text = String::from(parts.next().unwrap_or(""));
+        for part in parts {
+            text.push_str(&arguments.next().unwrap_or_default());
+            text.push_str(part);
+        }
+        text
+    }
+}
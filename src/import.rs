@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::mem;
+use std::path::Path;
+
+use flate2::read::DeflateDecoder;
+use serde::Deserialize;
+use serde_json;
+
+use date::{Date, Period};
+use resume::{Entry, Field, Job, Resume, Skill};
+
+/// Level given to skills whose level is not known, such as those of LinkedIn, to be adjusted.
+const UNKNOWN_LEVEL: u16 = 50;
+
+/// A resume from what another tool exported: a JSON Resume file, or a LinkedIn data export,
+/// as the zip archive it is downloaded as or the folder it unzips to.
+pub fn import(path: &Path) -> Result<Resume, String> {
+    let resume = if path.is_dir() || path.extension().is_some_and(|extension| extension == "zip") {
+        linkedin(path)
+    } else {
+        json_resume(path)
+    };
+    resume.map_err(|error| format!("Cannot import {}: {}", path.display(), error))
+}
+
+fn field(label: &str, value: &str) -> Field {
+    Field {
+        label: String::from(label),
+        value: String::from(value),
+    }
+}
+
+fn entry(title: String, period: Option<Period>, description: String) -> Entry {
+    Entry {
+        title,
+        period,
+        skills: Vec::new(),
+        audiences: Vec::new(),
+        description,
+    }
+}
+
+/// The non-empty parts of a text, joined.
+fn join(parts: &[&str], separator: &str) -> String {
+    parts.iter().map(|part| part.trim()).filter(|part| !part.is_empty()).collect::<Vec<&str>>().join(separator)
+}
+
+/// The period between two dates, none without a start, of the item called `name` for the errors.
+fn period<F>(name: &str, start: &str, end: &str, parse: F) -> Result<Option<Period>, String>
+where
+    F: Fn(&str) -> Option<Date>,
+{
+    let date = |text: &str| match text.trim() {
+        "" => Ok(None),
+        text => parse(text).map(Some).ok_or_else(|| format!("invalid date: {}", text)),
+    };
+    match (date(start)?, date(end)?) {
+        (Some(start), Some(end)) if end.last_month() < start.first_month() => Err(format!("{} ends before it starts", name)),
+        (Some(start), end) => Ok(Some(Period::new(start, end))),
+        (None, _) => Ok(None),
+    }
+}
+
+fn job(role: &str, company: &str, location: &str, period: Option<Period>, description: String) -> Result<Job, String> {
+    let period = period.ok_or_else(|| format!("{} at {} has no start date", role, company))?;
+    Ok(Job {
+        period,
+        role: String::from(role.trim()),
+        company: String::from(company.trim()),
+        location: String::from(location.trim()),
+        skills: Vec::new(),
+        audiences: Vec::new(),
+        description,
+    })
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct JsonResume {
+    basics: Basics,
+    work: Vec<Work>,
+    volunteer: Vec<Work>,
+    education: Vec<Education>,
+    skills: Vec<SkillArea>,
+    languages: Vec<Language>,
+    projects: Vec<Project>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Basics {
+    name: String,
+    label: String,
+    email: String,
+    phone: String,
+    #[serde(alias = "website")]
+    url: String,
+    summary: String,
+    location: Location,
+    profiles: Vec<Profile>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Location {
+    city: String,
+    region: String,
+    country_code: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Profile {
+    network: String,
+    username: String,
+    url: String,
+}
+
+/// A job, or volunteer work, which has an organization instead of a company name.
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Work {
+    #[serde(alias = "company", alias = "organization")]
+    name: String,
+    position: String,
+    location: String,
+    start_date: String,
+    end_date: String,
+    summary: String,
+    highlights: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Education {
+    institution: String,
+    area: String,
+    study_type: String,
+    start_date: String,
+    end_date: String,
+    courses: Vec<String>,
+}
+
+/// Skills of a kind, the keywords being the skills themselves.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct SkillArea {
+    name: String,
+    level: String,
+    keywords: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Language {
+    language: String,
+    fluency: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Project {
+    name: String,
+    description: String,
+    url: String,
+    start_date: String,
+    end_date: String,
+    highlights: Vec<String>,
+}
+
+/// A JSON Resume date, such as "2014-06-29", to the month.
+fn iso_date(text: &str) -> Option<Date> {
+    text.get(..7).unwrap_or(text).parse().ok()
+}
+
+/// A level out of 100 for the words JSON Resume files give levels with.
+fn level(word: &str) -> u16 {
+    match word.trim().to_lowercase().as_str() {
+        "master" | "expert" => 90,
+        "advanced" => 75,
+        "intermediate" => 55,
+        "beginner" | "basic" | "novice" => 30,
+        _ => UNKNOWN_LEVEL,
+    }
+}
+
+/// A summary followed by its highlights, one paragraph each.
+fn paragraphs(summary: &str, highlights: &[String]) -> String {
+    let mut paragraphs = vec![summary.trim()];
+    paragraphs.extend(highlights.iter().map(|highlight| highlight.trim()));
+    join(&paragraphs, "\n")
+}
+
+/// A resume from a file of the JSON Resume schema, https://jsonresume.org/schema.
+fn json_resume(path: &Path) -> Result<Resume, String> {
+    let text = fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let json: JsonResume = serde_json::from_str(&text).map_err(|error| error.to_string())?;
+    let basics = &json.basics;
+    let mut resume = Resume {
+        name: basics.name.clone(),
+        about: basics.summary.clone(),
+        ..Resume::default()
+    };
+    resume.objective.headline = basics.label.clone();
+    let location = &basics.location;
+    let place = join(&[&location.city, &location.region, &location.country_code], ", ");
+    if !place.is_empty() {
+        resume.information.push(field("Currently based in", &place));
+    }
+    for (label, value) in &[("Email", &basics.email), ("Phone", &basics.phone), ("Website", &basics.url)] {
+        if !value.is_empty() {
+            resume.contact.push(field(label, value));
+        }
+    }
+    for profile in &basics.profiles {
+        let value = if profile.url.is_empty() { &profile.username } else { &profile.url };
+        resume.contact.push(field(&profile.network, value));
+    }
+    resume.languages = json.languages.iter().map(|language| field(&language.language, &language.fluency)).collect();
+    for area in &json.skills {
+        let level = level(&area.level);
+        let skill = |name: &str, category: &str| Skill {
+            name: String::from(name.trim()),
+            category: String::from(category.trim()),
+            level,
+            learning: false,
+            audiences: Vec::new(),
+        };
+        if area.keywords.is_empty() {
+            resume.skills.push(skill(&area.name, ""));
+        } else {
+            resume.skills.extend(area.keywords.iter().map(|keyword| skill(keyword, &area.name)));
+        }
+    }
+    for work in &json.work {
+        let name = format!("{} at {}", work.position, work.name);
+        let period = period(&name, &work.start_date, &work.end_date, iso_date)?;
+        resume.experience.push(job(&work.position, &work.name, &work.location, period, paragraphs(&work.summary, &work.highlights))?);
+    }
+    for work in &json.volunteer {
+        let title = join(&[&work.position, &work.name], ", ");
+        let period = period(&title, &work.start_date, &work.end_date, iso_date)?;
+        resume.volunteering.push(entry(title, period, paragraphs(&work.summary, &work.highlights)));
+    }
+    for education in &json.education {
+        let title = join(&[&education.study_type, &education.area, &education.institution], ", ");
+        let period = period(&title, &education.start_date, &education.end_date, iso_date)?;
+        resume.education.push(entry(title, period, String::new()));
+        resume.courses.extend(education.courses.iter().map(|course| entry(course.clone(), None, String::new())));
+    }
+    for project in &json.projects {
+        let period = period(&project.name, &project.start_date, &project.end_date, iso_date)?;
+        let mut description = paragraphs(&project.description, &project.highlights);
+        if !project.url.is_empty() {
+            description = join(&[&description, &project.url], "\n");
+        }
+        resume.projects.push(entry(project.name.clone(), period, description));
+    }
+    Ok(resume)
+}
+
+/// Reads a number of `size` bytes, least significant first.
+fn number(bytes: &[u8], at: usize, size: usize) -> Option<usize> {
+    let bytes = bytes.get(at..at + size)?;
+    Some(bytes.iter().rev().fold(0, |number, &byte| number << 8 | byte as usize))
+}
+
+/// The CSV files of a zip archive, by file name, with what they hold. Only stored and
+/// deflated files can be read, which is what zip tools write.
+fn unzip(bytes: &[u8]) -> Option<HashMap<String, String>> {
+    // The end of central directory record, before a comment of up to 64 KiB
+    let end = (bytes.len().saturating_sub(22 + 0xffff)..bytes.len().saturating_sub(21)).rev()
+        .find(|&at| bytes[at..].starts_with(b"PK\x05\x06"))?;
+    let count = number(bytes, end + 10, 2)?;
+    let mut at = number(bytes, end + 16, 4)?;
+    let mut files = HashMap::new();
+    for _ in 0..count {
+        if !bytes.get(at..)?.starts_with(b"PK\x01\x02") {
+            return None;
+        }
+        let method = number(bytes, at + 10, 2)?;
+        let size = number(bytes, at + 20, 4)?;
+        let name_length = number(bytes, at + 28, 2)?;
+        let offset = number(bytes, at + 42, 4)?;
+        let name = String::from_utf8_lossy(bytes.get(at + 46..at + 46 + name_length)?).into_owned();
+        at += 46 + name_length + number(bytes, at + 30, 2)? + number(bytes, at + 32, 2)?;
+        let name = match name.rsplit('/').next() {
+            Some(name) if name.ends_with(".csv") => String::from(name),
+            _ => continue,
+        };
+        // The data follows the local header, whose extra field may differ from the central one
+        let start = offset + 30 + number(bytes, offset + 26, 2)? + number(bytes, offset + 28, 2)?;
+        let data = bytes.get(start..start + size)?;
+        let text = match method {
+            0 => data.to_vec(),
+            8 => {
+                let mut text = Vec::new();
+                DeflateDecoder::new(data).read_to_end(&mut text).ok()?;
+                text
+            }
+            _ => continue,
+        };
+        files.insert(name, String::from_utf8_lossy(&text).into_owned());
+    }
+    Some(files)
+}
+
+/// The rows of a CSV file, each by the names of the columns in the first line.
+fn csv(text: &str) -> Vec<HashMap<String, String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(mem::take(&mut field));
+                records.push(mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    let mut records = records.into_iter();
+    let header = records.next().unwrap_or_default();
+    records.map(|record| header.iter().cloned().zip(record).collect()).collect()
+}
+
+/// The value of a column of a CSV row, empty when it has none.
+fn column<'a>(row: &'a HashMap<String, String>, name: &str) -> &'a str {
+    row.get(name).map_or("", |value| value.trim())
+}
+
+/// A resume from the CSV files of a LinkedIn data export. LinkedIn has no skill levels, the
+/// skills are given the same one.
+fn linkedin(path: &Path) -> Result<Resume, String> {
+    let files = if path.is_dir() {
+        let entries = fs::read_dir(path).map_err(|error| error.to_string())?;
+        let mut files = HashMap::new();
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if let Some(name) = path.file_name().and_then(|name| name.to_str()).filter(|name| name.ends_with(".csv")) {
+                files.insert(String::from(name), fs::read_to_string(&path).map_err(|error| error.to_string())?);
+            }
+        }
+        files
+    } else {
+        let bytes = fs::read(path).map_err(|error| error.to_string())?;
+        unzip(&bytes).ok_or("not a zip archive, or a damaged one")?
+    };
+    let table = |name: &str| files.get(name).map(|text| csv(text)).unwrap_or_default();
+    let profile = table("Profile.csv").into_iter().next().ok_or("no Profile.csv, it is not a LinkedIn data export")?;
+    let mut resume = Resume {
+        name: join(&[column(&profile, "First Name"), column(&profile, "Last Name")], " "),
+        about: String::from(column(&profile, "Summary")),
+        ..Resume::default()
+    };
+    resume.objective.headline = String::from(column(&profile, "Headline"));
+    let place = column(&profile, "Geo Location");
+    if !place.is_empty() {
+        resume.information.push(field("Currently based in", place));
+    }
+    let mut emails = table("Email Addresses.csv");
+    emails.sort_by_key(|row| column(row, "Primary") != "Yes");
+    resume.contact.extend(emails.iter().map(|row| field("Email", column(row, "Email Address"))));
+    resume.contact.extend(table("PhoneNumbers.csv").iter().map(|row| field("Phone", column(row, "Number"))));
+    // Lists such as "[PERSONAL:https://example.com],[Blog:https://example.com/blog]"
+    for website in column(&profile, "Websites").split(',') {
+        let website = website.trim().trim_start_matches('[').trim_end_matches(']');
+        let address = website.find(":http").map_or(website, |at| &website[at + 1..]);
+        if !address.is_empty() {
+            resume.contact.push(field("Website", address));
+        }
+    }
+    for handle in column(&profile, "Twitter Handles").split(',') {
+        let handle = handle.trim().trim_start_matches('[').trim_end_matches(']');
+        if !handle.is_empty() {
+            resume.contact.push(field("Twitter", &format!("https://twitter.com/{}", handle)));
+        }
+    }
+    resume.languages = table("Languages.csv").iter().map(|row| field(column(row, "Name"), column(row, "Proficiency"))).collect();
+    resume.skills = table("Skills.csv").iter()
+        .map(|row| Skill {
+            name: String::from(column(row, "Name")),
+            category: String::new(),
+            level: UNKNOWN_LEVEL,
+            learning: false,
+            audiences: Vec::new(),
+        })
+        .collect();
+    for row in &table("Positions.csv") {
+        let (role, company) = (column(row, "Title"), column(row, "Company Name"));
+        let name = format!("{} at {}", role, company);
+        let period = period(&name, column(row, "Started On"), column(row, "Finished On"), Date::parse_written)?;
+        resume.experience.push(job(role, company, column(row, "Location"), period, String::from(column(row, "Description")))?);
+    }
+    for row in &table("Education.csv") {
+        let title = join(&[column(row, "Degree Name"), column(row, "School Name")], ", ");
+        let period = period(&title, column(row, "Start Date"), column(row, "End Date"), Date::parse_written)?;
+        resume.education.push(entry(title, period, String::from(column(row, "Notes"))));
+    }
+    resume.courses = table("Courses.csv").iter().map(|row| entry(String::from(column(row, "Name")), None, String::new())).collect();
+    for row in &table("Projects.csv") {
+        let period = period(column(row, "Title"), column(row, "Started On"), column(row, "Finished On"), Date::parse_written)?;
+        let description = join(&[column(row, "Description"), column(row, "Url")], "\n");
+        resume.projects.push(entry(String::from(column(row, "Title")), period, description));
+    }
+    Ok(resume)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn periods_end_after_they_start() {
+        let name = "Developer at Acme";
+        assert_eq!(period(name, "2017-03-01", "2016-10-31", iso_date), Err(String::from("Developer at Acme ends before it starts")));
+        assert_eq!(period(name, "Mar 2017", "Oct 2016", Date::parse_written), Err(String::from("Developer at Acme ends before it starts")));
+        let started = Date { year: 2017, month: Some(3) };
+        assert_eq!(period(name, "2017-03-01", "2017-03-31", iso_date), Ok(Some(Period::new(started, Some(started)))));
+        assert_eq!(period(name, "Mar 2017", "", Date::parse_written), Ok(Some(Period::new(started, None))));
+        assert_eq!(period(name, "", "Oct 2016", Date::parse_written), Ok(None));
+    }
+}
@@ -0,0 +1,195 @@
+use date::Date;
+use locale::Locale;
+use markup;
+use matching;
+use resume::{Field, Resume};
+
+/// Jobs a cover letter tells about, the most relevant to the position.
+const RELEVANT_JOBS: usize = 2;
+/// Skills named when none of them match the keywords, the first ones of the resume.
+const DEFAULT_SKILLS: usize = 3;
+
+/// What a cover letter is written as.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Text,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "markdown" | "md" => Some(Format::Markdown),
+            "text" | "txt" => Some(Format::Text),
+            _ => None,
+        }
+    }
+
+    /// Resume text with its links written for the format.
+    fn text(self, text: &str) -> String {
+        match self {
+            Format::Markdown => markup::markdown(text),
+            Format::Text => markup::plain(text),
+        }
+    }
+
+    fn strong(self, text: &str) -> String {
+        match self {
+            Format::Markdown => format!("**{}**", text),
+            Format::Text => String::from(text),
+        }
+    }
+}
+
+/// Reads a job-info file: one "key: value" line per detail of the position, such as
+/// "company: ACME", "role: Backend Developer" or "keywords: Go, Kubernetes". Empty lines and
+/// lines starting with '#' are skipped.
+pub fn job_info(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut info = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.find(':') {
+            Some(colon) => info.push((line[..colon].trim().to_lowercase(), String::from(line[colon + 1..].trim()))),
+            None => return Err(format!("Line {} of the job info is not \"key: value\": {}", number + 1, line)),
+        }
+    }
+    Ok(info)
+}
+
+/// "a, b and c"
+fn enumeration(items: &[String], locale: &Locale) -> String {
+    match items.split_last() {
+        None => String::new(),
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => locale.lang.format("{} and {}", &[&rest.join(", "), last]),
+    }
+}
+
+fn list(items: &[String]) -> String {
+    items.iter().map(|item| format!("- {}", item)).collect::<Vec<String>>().join("\n")
+}
+
+fn fields(fields: &[Field], format: Format) -> String {
+    let items = fields.iter()
+        .map(|field| format!("{}: {}", field.label, format.text(&field.value)))
+        .collect::<Vec<String>>();
+    match format {
+        Format::Markdown => list(&items),
+        Format::Text => items.join("\n"),
+    }
+}
+
+/// Values of the placeholders, from the resume and from the job info, which comes last so
+/// it can add its own.
+fn values(resume: &Resume, info: &[(String, String)], format: Format, locale: &Locale, today: Date, day: u32)
+          -> Vec<(String, String)> {
+    let detail = |key: &str| info.iter().find(|(k, _)| k == key).map(|(_, value)| value.as_str()).unwrap_or("");
+    let report = matching::report(resume, &format!("{} {}", detail("role"), detail("keywords")));
+    let mut skills = report.skills.iter().chain(&report.technologies).cloned().collect::<Vec<String>>();
+    if skills.is_empty() {
+        skills = resume.skills.iter().take(DEFAULT_SKILLS).map(|skill| skill.name.clone()).collect();
+    }
+    let mut jobs = report.roles.iter()
+        .filter(|&&(_, relevance)| relevance > 0)
+        .map(|&(i, _)| i)
+        .take(RELEVANT_JOBS)
+        .collect::<Vec<usize>>();
+    if jobs.is_empty() {
+        jobs = (0..resume.experience.len().min(RELEVANT_JOBS)).collect();
+    }
+    let experience = jobs.into_iter()
+        .map(|i| {
+            let job = &resume.experience[i];
+            let description = job.description.lines().map(str::trim).collect::<Vec<&str>>().join(" ");
+            locale.lang.format("{} at {} ({}, {}): {}",
+                               &[&format.strong(&job.role), &job.company, &locale.period(job.period),
+                                 &locale.duration(job.period.length(today)), &format.text(&description)])
+        })
+        .collect::<Vec<String>>();
+    let goals = resume.objective.goals.iter().map(|goal| goal.text.clone()).collect::<Vec<String>>();
+    let mut values = vec![
+        (String::from("date"), locale.day(today, day)),
+        (String::from("name"), resume.name.clone()),
+        (String::from("contact"), fields(&resume.contact, format)),
+        (String::from("skills"), enumeration(&skills, locale)),
+        (String::from("experience"), list(&experience)),
+        (String::from("objective"), format!("{}\n\n{}", resume.objective.intro, list(&goals))),
+    ];
+    values.extend(info.iter().cloned());
+    values
+}
+
+/// Replaces the `{{placeholder}}` of the template, failing on the ones it has no value for.
+fn fill(template: &str, values: &[(String, String)]) -> Result<String, String> {
+    let mut filled = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = match rest[start..].find("}}") {
+            Some(end) => start + end,
+            None => break,
+        };
+        let key = rest[start + 2..end].trim().to_lowercase();
+        let value = match values.iter().rev().find(|(k, _)| *k == key) {
+            Some((_, value)) => value,
+            None => {
+                let keys = values.iter().map(|(k, _)| k.as_str()).collect::<Vec<&str>>();
+                return Err(format!("Unknown placeholder {{{{{}}}}}, the known ones are {}", key, keys.join(", ")));
+            }
+        };
+        filled.push_str(&rest[..start]);
+        filled.push_str(value);
+        rest = &rest[end + 2..];
+    }
+    filled.push_str(rest);
+    Ok(filled)
+}
+
+/// A cover letter for the position of the job info, from the template.
+///
+/// The template can use `{{date}}` (today), `{{name}}`, `{{contact}}`, `{{skills}}` (those matching
+/// the role and the keywords), `{{experience}}` (the most relevant jobs), `{{objective}}` and every
+/// key of the job info, such as `{{company}}` and `{{role}}`. Dates and the few words between the
+/// values are written for the locale.
+pub fn write(resume: &Resume, template: &str, job_info: &str, format: Format, locale: &Locale, today: Date, day: u32)
+             -> Result<String, String> {
+    let info = self::job_info(job_info)?;
+    fill(template, &values(resume, &info, format, locale, today, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use i18n::Lang;
+
+    fn values(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|&(key, value)| (String::from(key), String::from(value))).collect()
+    }
+
+    #[test]
+    fn placeholders_are_replaced_by_their_values() {
+        let values = values(&[("name", "Daisy T"), ("company", "ACME"), ("company", "ACME GmbH")]);
+        assert_eq!(fill("Dear {{ Company }},\n\n{{name}} {{name}}", &values), Ok(String::from("Dear ACME GmbH,\n\nDaisy T Daisy T")));
+        // Single braces and an unclosed placeholder are left alone
+        assert_eq!(fill("{a} {{name}} {{b", &values), Ok(String::from("{a} Daisy T {{b")));
+        assert_eq!(fill("{{role}}", &values), Err(String::from("Unknown placeholder {{role}}, the known ones are name, company, company")));
+    }
+
+    #[test]
+    fn job_info_is_read_by_key() {
+        assert_eq!(job_info("# A comment\n\nCompany: ACME\nrole:  Backend Developer \n"),
+                   Ok(values(&[("company", "ACME"), ("role", "Backend Developer")])));
+        assert_eq!(job_info("company ACME"), Err(String::from("Line 1 of the job info is not \"key: value\": company ACME")));
+    }
+
+    #[test]
+    fn letters_name_the_relevant_skills() {
+        let resume = Resume::builtin();
+        let today = Date { year: 2020, month: Some(3) };
+        let letter = write(&resume, "{{date}}: {{skills}} for {{role}} at {{company}}", "company: ACME\nrole: Go Developer\nkeywords: Ruby",
+                           Format::Text, Locale::of(Lang::English), today, 18);
+        assert_eq!(letter, Ok(String::from("March 18, 2020: Go and Ruby for Go Developer at ACME")));
+    }
+}
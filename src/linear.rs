@@ -0,0 +1,286 @@
+use std::io::{self, BufRead, Write};
+
+use date::Date;
+use i18n::Lang;
+use locale::Locale;
+use markup;
+use resume::{Entry, Field, Job, Resume};
+
+const HELP: &str = "Type a command and press Enter:
+  Enter or n   next item
+  p            previous item
+  r            repeat the current item
+  a            read the rest of the section
+  s            next section
+  b            previous section
+  1 to 7       go to that section
+  l            list the sections
+  h            this help
+  q            quit";
+
+/// A piece of the resume that is read on its own.
+struct Item {
+    label: String,
+    text: String,
+}
+
+struct Section {
+    title: &'static str,
+    items: Vec<Item>,
+}
+
+fn item(label: &str, text: &str) -> Item {
+    Item {
+        label: String::from(label),
+        text: markup::plain(text),
+    }
+}
+
+fn fields(label: &str, fields: &[Field]) -> Item {
+    let text = fields.iter()
+        .map(|field| format!("{}: {}", field.label, field.value))
+        .collect::<Vec<String>>()
+        .join("\n");
+    item(label, &text)
+}
+
+/// Items for a list of entries, with their group in the label since a section has several.
+fn entries(group: &str, entries: &[Entry], today: Option<Date>, locale: &Locale) -> Vec<Item> {
+    entries.iter()
+        .map(|entry| item(&format!("{}, {}", group, entry.heading(today, locale)), &entry.description))
+        .collect()
+}
+
+fn jobs(jobs: &[Job], today: Date, locale: &Locale) -> Vec<Item> {
+    jobs.iter().map(|job| item(&job.title(today, locale), &job.description)).collect()
+}
+
+/// The same sections as the tabs, flattened into labelled items.
+fn sections(resume: &Resume, lang: Lang, locale: &Locale, today: Date) -> Vec<Section> {
+    let mut skills = resume.skills.iter()
+        .map(|skill| {
+            let usage = resume.usage(&skill.name, today);
+            item(&skill.label(lang), &lang.format("{} out of 100, {}", &[&skill.level, &usage.summary(lang, locale)]))
+        })
+        .collect::<Vec<Item>>();
+    skills.extend(resume.others.iter().map(|group| item(&group.title, &group.items.join(", "))));
+    let mut education = entries(lang.text("Education"), &resume.education, Some(today), locale);
+    education.extend(entries(lang.text("Continuing Education"), &resume.courses, None, locale));
+    let mut projects = entries(lang.text("Personal Projects"), &resume.projects, None, locale);
+    projects.extend(entries(lang.text("Volunteer Work"), &resume.volunteering, None, locale));
+    projects.extend(entries(lang.text("Open-Source Contributions"), &resume.contributions, None, locale));
+    let mut objective = vec![item(lang.text("What I am looking for?"), &resume.objective.headline)];
+    let goals = resume.objective.goals.iter().map(|goal| goal.text.as_str()).collect::<Vec<&str>>();
+    objective.push(item(&resume.objective.intro, &goals.join("\n")));
+    vec![
+        Section {
+            title: lang.text("Home"),
+            items: vec![item(&lang.format("{}'s resume", &[&resume.name]), lang.text(HELP))],
+        },
+        Section {
+            title: lang.text("About"),
+            items: vec![
+                fields(lang.text("Information"), &resume.information),
+                fields(lang.text("Languages"), &resume.languages),
+                fields(lang.text("Contact"), &resume.contact),
+                item(lang.text("About me"), &resume.about),
+                fields(lang.text("Experience"), &resume.experience_totals(today, lang, locale)),
+            ],
+        },
+        Section {
+            title: lang.text("Skills"),
+            items: skills,
+        },
+        Section {
+            title: lang.text("Experience"),
+            items: jobs(&resume.experience, today, locale),
+        },
+        Section {
+            title: lang.text("Education"),
+            items: education,
+        },
+        Section {
+            title: lang.text("Projects"),
+            items: projects,
+        },
+        Section {
+            title: lang.text("Objective"),
+            items: objective,
+        },
+    ]
+}
+
+struct Reader<W: Write> {
+    out: W,
+    sections: Vec<Section>,
+    lang: Lang,
+    section: usize,
+    item: usize,
+}
+
+impl<W: Write> Reader<W> {
+    fn announce_section(&mut self) -> io::Result<()> {
+        let section = &self.sections[self.section];
+        let count = section.items.len();
+        let announcement = if count == 1 { "Section {} of {}: {}, {} item." } else { "Section {} of {}: {}, {} items." };
+        let announcement = self.lang.format(announcement, &[&(self.section + 1), &self.sections.len(), &section.title, &count]);
+        writeln!(self.out, "\n{}", announcement)
+    }
+
+    /// Reads the current item, or says that there is none: resumes from files may leave
+    /// sections empty.
+    fn read_item(&mut self) -> io::Result<()> {
+        let section = &self.sections[self.section];
+        if section.items.is_empty() {
+            return writeln!(self.out, "\n{}", self.lang.format("No items in section {}.", &[&section.title]));
+        }
+        let item = &section.items[self.item];
+        let heading = self.lang.format("Item {} of {}: {}", &[&(self.item + 1), &section.items.len(), &item.label]);
+        writeln!(self.out, "\n{}\n{}", heading, item.text)
+    }
+
+    fn go_to_section(&mut self, section: usize) -> io::Result<()> {
+        self.section = section;
+        self.item = 0;
+        self.announce_section()?;
+        self.read_item()
+    }
+
+    fn next_item(&mut self) -> io::Result<()> {
+        if self.item + 1 < self.sections[self.section].items.len() {
+            self.item += 1;
+            self.read_item()
+        } else {
+            let title = self.sections[self.section].title;
+            writeln!(self.out, "\n{}", self.lang.format("End of section {}. Type s for the next section.", &[&title]))
+        }
+    }
+
+    fn previous_item(&mut self) -> io::Result<()> {
+        if self.item > 0 {
+            self.item -= 1;
+            self.read_item()
+        } else {
+            let title = self.sections[self.section].title;
+            writeln!(self.out, "\n{}", self.lang.format("Start of section {}. Type b for the previous section.", &[&title]))
+        }
+    }
+
+    fn list_sections(&mut self) -> io::Result<()> {
+        writeln!(self.out)?;
+        for (i, section) in self.sections.iter().enumerate() {
+            writeln!(self.out, "{}: {}", i + 1, section.title)?;
+        }
+        Ok(())
+    }
+
+    /// Handles a command, returns false when the reader should stop.
+    fn command(&mut self, command: &str) -> io::Result<bool> {
+        let count = self.sections.len();
+        let empty = self.sections[self.section].items.is_empty();
+        match command {
+            // Nothing to read in an empty section, which was said when going to it
+            "" | "n" | "r" | "a" if empty => {}
+            "" | "n" => self.next_item()?,
+            "p" => self.previous_item()?,
+            "r" => self.read_item()?,
+            "a" => {
+                while self.item + 1 < self.sections[self.section].items.len() {
+                    self.item += 1;
+                    self.read_item()?;
+                }
+                let title = self.sections[self.section].title;
+                writeln!(self.out, "\n{}", self.lang.format("End of section {}.", &[&title]))?;
+            }
+            "s" => {
+                let section = (self.section + 1) % count;
+                self.go_to_section(section)?;
+            }
+            "b" => {
+                let section = (self.section + count - 1) % count;
+                self.go_to_section(section)?;
+            }
+            "l" => self.list_sections()?,
+            "h" | "?" => writeln!(self.out, "\n{}", self.lang.text(HELP))?,
+            "q" => return Ok(false),
+            _ => match command.parse::<usize>() {
+                Ok(n) if n >= 1 && n <= count => self.go_to_section(n - 1)?,
+                _ => writeln!(self.out, "\n{}", self.lang.format("Unknown command {}. Type h for help.", &[&command]))?,
+            },
+        }
+        Ok(true)
+    }
+}
+
+/// Reads the resume as plain text, one item at a time, driven by commands typed on stdin.
+pub fn run(resume: &Resume, lang: Lang, locale: &Locale) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut reader = Reader {
+        out: stdout.lock(),
+        sections: sections(resume, lang, locale, Date::today()),
+        lang,
+        section: 0,
+        item: 0,
+    };
+    reader.go_to_section(0)?;
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        write!(reader.out, "\n{}", lang.text("Command (h for help): "))?;
+        reader.out.flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+        if !reader.command(line.trim())? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use locale::Locale;
+
+    fn reader(resume: &Resume) -> Reader<Vec<u8>> {
+        Reader {
+            out: Vec::new(),
+            sections: sections(resume, Lang::English, Locale::of(Lang::English), Date::year(2020)),
+            lang: Lang::English,
+            section: 0,
+            item: 0,
+        }
+    }
+
+    fn output(reader: &mut Reader<Vec<u8>>) -> String {
+        String::from_utf8(reader.out.split_off(0)).unwrap()
+    }
+
+    #[test]
+    fn empty_sections_have_no_items_to_read() {
+        let resume = Resume {
+            name: String::from("Nobody"),
+            ..Resume::default()
+        };
+        let mut reader = reader(&resume);
+        reader.command("4").unwrap();
+        assert_eq!(output(&mut reader), "\nSection 4 of 7: Experience, 0 items.\n\nNo items in section Experience.\n");
+        for command in &["", "n", "r", "a", "p"] {
+            reader.command(command).unwrap();
+        }
+        assert_eq!(output(&mut reader), "\nStart of section Experience. Type b for the previous section.\n");
+        reader.command("s").unwrap();
+        assert!(output(&mut reader).contains("No items in section Education."));
+    }
+
+    #[test]
+    fn items_are_read_in_turn() {
+        let mut reader = reader(&Resume::builtin());
+        reader.command("2").unwrap();
+        assert!(output(&mut reader).ends_with("Item 1 of 5: Information\nName: Daisy T\nNationality: Canadian\nCurrently based in: Berlin, Germany\n"));
+        reader.command("n").unwrap();
+        assert!(output(&mut reader).starts_with("\nItem 2 of 5: Languages\n"));
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use date::Period;
+use locale::Locale;
+use markup;
+use resume::Resume;
+use spelling::{self, Checker};
+
+/// Words past which a bullet is too long to be read at a glance.
+const MAX_BULLET_WORDS: usize = 30;
+
+/// Contact details applicant tracking systems look for, whatever the case of their labels.
+const CONTACT_FIELDS: &[&str] = &["Email", "Phone", "LinkedIn"];
+
+/// Headings applicant tracking systems recognize, in lowercase.
+const STANDARD_HEADINGS: &[&str] = &[
+    "summary", "profile", "objective", "career objective", "contact", "contact information",
+    "information", "personal information", "languages", "skills", "technical skills",
+    "programming languages", "experience", "work experience", "professional experience",
+    "employment history", "education", "continuing education", "certifications", "courses",
+    "projects", "personal projects", "volunteer work", "volunteer experience", "publications",
+    "open-source contributions",
+];
+
+/// Standard headings for the non-standard ones, in lowercase.
+const HEADING_SUGGESTIONS: &[(&str, &str)] = &[
+    ("about me", "Summary"),
+    ("others", "Additional Skills"),
+    ("what i am looking for?", "Objective"),
+];
+
+/// Auxiliary verbs that make a participle after them passive.
+const AUXILIARIES: &[&str] = &["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// Participles that do not end in "ed".
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "built", "done", "given", "held", "kept", "known", "led", "made", "run", "seen", "shown",
+    "taken", "taught", "written",
+];
+
+/// Phrases that tell about duties instead of achievements.
+const WEAK_PHRASES: &[&str] = &["responsible for", "duties included", "tasked with"];
+
+/// An issue with the resume, with where it is.
+pub struct Warning {
+    pub section: &'static str,
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} > {}: {}", self.section, self.item, self.message)
+    }
+}
+
+fn warning(section: &'static str, item: &str, message: String) -> Warning {
+    Warning {
+        section,
+        item: String::from(item),
+        message,
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '-'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Jobs and entries with their dates, if any.
+fn dated(resume: &Resume) -> Vec<(&'static str, String, Option<Period>)> {
+    let mut dated = resume.experience.iter()
+        .map(|job| ("Experience", format!("{}, {}", job.role, job.company), Some(job.period)))
+        .collect::<Vec<(&'static str, String, Option<Period>)>>();
+    for entry in resume.education.iter().chain(&resume.courses) {
+        dated.push(("Education", entry.title.clone(), entry.period));
+    }
+    for entry in resume.projects.iter().chain(&resume.volunteering).chain(&resume.contributions) {
+        dated.push(("Projects", entry.title.clone(), entry.period));
+    }
+    dated
+}
+
+fn dates(resume: &Resume, locale: &Locale) -> Vec<Warning> {
+    let dated = dated(resume);
+    let mut warnings = dated.iter()
+        .filter(|(_, _, period)| period.is_none())
+        .map(|(section, item, _)| warning(section, item, String::from("has no dates")))
+        .collect::<Vec<Warning>>();
+    // Dates are expected to all give the month, or all give the year only, as most of them do
+    let periods = dated.iter().filter_map(|(section, item, period)| period.map(|period| (section, item, period)));
+    let with_month = |period: &Period| period.start.month.is_some() || period.end.map(|end| end.month.is_some()).unwrap_or(false);
+    let months = periods.clone().filter(|(_, _, period)| with_month(period)).count();
+    let years = periods.clone().count() - months;
+    for (section, item, period) in periods {
+        if with_month(&period) && months < years {
+            warnings.push(warning(section, item, format!("gives months in its dates ({}) unlike the others", locale.period(period))));
+        } else if !with_month(&period) && years < months {
+            warnings.push(warning(section, item, format!("only gives years in its dates ({}) unlike the others", locale.period(period))));
+        }
+    }
+    warnings
+}
+
+fn bullets(resume: &Resume) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for passage in resume.passages() {
+        if passage.section == "About" || passage.section == "Objective" {
+            continue;
+        }
+        let text = markup::plain(passage.text);
+        for bullet in text.split(&['\n', ';'][..]) {
+            let count = words(bullet).len();
+            if count > MAX_BULLET_WORDS {
+                let message = format!("a bullet has {} words, more than {}: \"{}...\"", count, MAX_BULLET_WORDS,
+                                      bullet.trim().split(' ').take(6).collect::<Vec<&str>>().join(" "));
+                warnings.push(warning(passage.section, &passage.item, message));
+            }
+        }
+    }
+    warnings
+}
+
+fn passive(resume: &Resume) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for passage in resume.passages() {
+        let words = words(&markup::plain(passage.text));
+        for (i, word) in words.iter().enumerate() {
+            if !AUXILIARIES.contains(&word.as_str()) {
+                continue;
+            }
+            // Adverbs may come in between, as in "was quickly adopted"
+            let next = words[i + 1..].iter().find(|next| !next.ends_with("ly"));
+            if let Some(participle) = next {
+                if participle.ends_with("ed") || IRREGULAR_PARTICIPLES.contains(&participle.as_str()) {
+                    let message = format!("passive phrasing \"{} {}\", say who did it", word, participle);
+                    warnings.push(warning(passage.section, &passage.item, message));
+                }
+            }
+        }
+        let text = words.join(" ");
+        for phrase in WEAK_PHRASES {
+            if text.contains(phrase) {
+                let message = format!("\"{}\" tells about duties, say what was achieved instead", phrase);
+                warnings.push(warning(passage.section, &passage.item, message));
+            }
+        }
+    }
+    warnings
+}
+
+fn contact(resume: &Resume) -> Vec<Warning> {
+    CONTACT_FIELDS.iter()
+        .filter(|name| !resume.contact.iter().any(|field| field.label.eq_ignore_ascii_case(name) && !field.value.is_empty()))
+        .map(|name| warning("About", "Contact", format!("no {} given", name)))
+        .collect()
+}
+
+/// The headings of the sections the resume has, as they are shown in the tabs and in the
+/// linear mode.
+fn section_names(resume: &Resume) -> Vec<&'static str> {
+    let sections = [
+        ("Information", !resume.information.is_empty()),
+        ("Languages", !resume.languages.is_empty()),
+        ("Contact", !resume.contact.is_empty()),
+        ("About me", !resume.about.is_empty()),
+        ("Skills", !resume.skills.is_empty()),
+        ("Others", !resume.others.is_empty()),
+        ("Experience", !resume.experience.is_empty()),
+        ("Education", !resume.education.is_empty()),
+        ("Continuing Education", !resume.courses.is_empty()),
+        ("Personal Projects", !resume.projects.is_empty()),
+        ("Volunteer Work", !resume.volunteering.is_empty()),
+        ("Open-Source Contributions", !resume.contributions.is_empty()),
+        ("What I am looking for?", !resume.objective.headline.is_empty()),
+    ];
+    sections.iter().filter(|&&(_, shown)| shown).map(|&(name, _)| name).collect()
+}
+
+fn headings(resume: &Resume) -> Vec<Warning> {
+    section_names(resume).into_iter()
+        .filter(|heading| !STANDARD_HEADINGS.contains(&heading.to_lowercase().as_str()))
+        .map(|heading| {
+            let suggestion = HEADING_SUGGESTIONS.iter()
+                .find(|&&(name, _)| name == heading.to_lowercase())
+                .map(|&(_, suggestion)| format!(", use \"{}\"", suggestion))
+                .unwrap_or_default();
+            warning("Headings", heading, format!("not a standard section name{}", suggestion))
+        })
+        .collect()
+}
+
+/// Words one edit away from known ones, the other unknown words being mostly names, which are
+/// left to the spellcheck command.
+fn typos(resume: &Resume, checker: &Checker) -> Vec<Warning> {
+    spelling::misspellings(resume, checker).into_iter()
+        .filter(|misspelling| !misspelling.suggestions.is_empty())
+        .map(|misspelling| {
+            let suggestions = misspelling.suggestions.iter().map(|word| format!("\"{}\"", word)).collect::<Vec<String>>();
+            let message = format!("\"{}\" looks like a typo, did you mean {}?", misspelling.word, suggestions.join(" or "));
+            warning(misspelling.section, &misspelling.item, message)
+        })
+        .collect()
+}
+
+/// What applicant tracking systems could trip over in the resume, with dates written for the
+/// locale and typos found with the dictionaries of the checker.
+pub fn lint(resume: &Resume, locale: &Locale, checker: &Checker) -> Vec<Warning> {
+    let mut warnings = dates(resume, locale);
+    warnings.extend(bullets(resume));
+    warnings.extend(passive(resume));
+    warnings.extend(contact(resume));
+    warnings.extend(headings(resume));
+    warnings.extend(typos(resume, checker));
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use date::Date;
+    use i18n::Lang;
+    use resume::{Field, Job};
+
+    fn job(role: &str, period: Period, description: &str) -> Job {
+        Job {
+            period,
+            role: String::from(role),
+            company: String::from("Acme"),
+            location: String::new(),
+            skills: Vec::new(),
+            audiences: Vec::new(),
+            description: String::from(description),
+        }
+    }
+
+    fn field(label: &str, value: &str) -> Field {
+        Field {
+            label: String::from(label),
+            value: String::from(value),
+        }
+    }
+
+    fn messages(resume: &Resume, lang: Lang) -> Vec<String> {
+        let checker = Checker::new(&[]).unwrap();
+        lint(resume, Locale::of(lang), &checker).iter().map(|warning| warning.to_string()).collect()
+    }
+
+    #[test]
+    fn a_clean_resume_has_no_warnings() {
+        let resume = Resume {
+            name: String::from("Ana"),
+            contact: vec![field("email", "ana@example.com"), field("PHONE", "+34 600 000 000"), field("Linkedin", "https://linkedin.com/in/ana")],
+            experience: vec![job("Engineer", Period::new(Date::year(2019), None), "Built the billing service.")],
+            ..Resume::default()
+        };
+        assert!(messages(&resume, Lang::English).is_empty());
+    }
+
+    #[test]
+    fn dates_are_written_for_the_locale() {
+        let resume = Resume {
+            contact: vec![field("Email", "ana@example.com"), field("Phone", "1"), field("LinkedIn", "ana")],
+            experience: vec![
+                job("Engineer", Period::new(Date::year(2019), None), ""),
+                job("Intern", Period::new(Date::year(2017), Some(Date::year(2018))), ""),
+                job("Tester", Period::new("2016-03".parse().unwrap(), Some("2016-10".parse().unwrap())), ""),
+            ],
+            ..Resume::default()
+        };
+        assert_eq!(messages(&resume, Lang::German),
+                   vec!["Experience > Tester, Acme: gives months in its dates (März 2016 – Okt. 2016) unlike the others"]);
+    }
+
+    #[test]
+    fn missing_contact_details_and_typos_are_reported() {
+        let resume = Resume {
+            contact: vec![field("E-mail", "ana@example.com"), field("Phone", "")],
+            about: String::from("Managment of the billing service."),
+            ..Resume::default()
+        };
+        assert_eq!(messages(&resume, Lang::English), vec![
+            "About > Contact: no Email given",
+            "About > Contact: no Phone given",
+            "About > Contact: no LinkedIn given",
+            "Headings > About me: not a standard section name, use \"Summary\"",
+            "About > About me: \"Managment\" looks like a typo, did you mean \"Management\"?",
+        ]);
+    }
+
+    #[test]
+    fn typos_of_the_builtin_resume_are_reported() {
+        let warnings = messages(&Resume::builtin(), Lang::English);
+        assert!(warnings.contains(&String::from("Objective > Receive and give mentorshop: \"mentorshop\" looks like a typo, did you mean \"mentorship\"?")),
+                "{:?}", warnings);
+    }
+}
@@ -0,0 +1,202 @@
+use date::{Date, Period};
+use i18n::Lang;
+
+/// Where the day goes in a date written out in full.
+#[derive(Clone, Copy, PartialEq)]
+enum DayOrder {
+    /// "October 18, 2026"
+    MonthFirst,
+    /// "18 October 2026"
+    DayFirst,
+    /// "18. Oktober 2026"
+    DayFirstWithPeriod,
+}
+
+/// Conventions dates and numbers are written with in a country.
+pub struct Locale {
+    /// Language and country, such as "en-US"
+    pub code: &'static str,
+    /// Language of the words it writes dates with
+    pub lang: Lang,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    /// End of a period still going on
+    present: &'static str,
+    day_order: DayOrder,
+    /// Singular and plural, for years then months
+    years: (&'static str, &'static str),
+    months_unit: (&'static str, &'static str),
+    /// Whether a space goes before the percent sign
+    spaced_percent: bool,
+}
+
+const ENGLISH_MONTHS: [&str; 12] = ["January", "February", "March", "April", "May", "June", "July", "August",
+                                    "September", "October", "November", "December"];
+const ENGLISH_SHORT_MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+const CANADIAN_SHORT_MONTHS: [&str; 12] = ["Jan.", "Feb.", "Mar.", "Apr.", "May", "Jun.", "Jul.", "Aug.", "Sep.", "Oct.", "Nov.", "Dec."];
+const GERMAN_MONTHS: [&str; 12] = ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August",
+                                   "September", "Oktober", "November", "Dezember"];
+const GERMAN_SHORT_MONTHS: [&str; 12] = ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."];
+const AUSTRIAN_MONTHS: [&str; 12] = ["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August",
+                                     "September", "Oktober", "November", "Dezember"];
+const AUSTRIAN_SHORT_MONTHS: [&str; 12] = ["Jän.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."];
+
+const LOCALES: &[Locale] = &[
+    Locale {
+        code: "en-US",
+        lang: Lang::English,
+        months: ENGLISH_MONTHS,
+        short_months: ENGLISH_SHORT_MONTHS,
+        present: "present",
+        day_order: DayOrder::MonthFirst,
+        years: ("yr", "yrs"),
+        months_unit: ("mo", "mos"),
+        spaced_percent: false,
+    },
+    Locale {
+        code: "en-GB",
+        lang: Lang::English,
+        months: ENGLISH_MONTHS,
+        short_months: ENGLISH_SHORT_MONTHS,
+        present: "present",
+        day_order: DayOrder::DayFirst,
+        years: ("yr", "yrs"),
+        months_unit: ("mo", "mos"),
+        spaced_percent: false,
+    },
+    Locale {
+        code: "en-CA",
+        lang: Lang::English,
+        months: ENGLISH_MONTHS,
+        short_months: CANADIAN_SHORT_MONTHS,
+        present: "present",
+        day_order: DayOrder::MonthFirst,
+        years: ("yr", "yrs"),
+        months_unit: ("mo", "mos"),
+        spaced_percent: false,
+    },
+    Locale {
+        code: "de-DE",
+        lang: Lang::German,
+        months: GERMAN_MONTHS,
+        short_months: GERMAN_SHORT_MONTHS,
+        present: "heute",
+        day_order: DayOrder::DayFirstWithPeriod,
+        years: ("J.", "J."),
+        months_unit: ("Mon.", "Mon."),
+        spaced_percent: true,
+    },
+    Locale {
+        code: "de-AT",
+        lang: Lang::German,
+        months: AUSTRIAN_MONTHS,
+        short_months: AUSTRIAN_SHORT_MONTHS,
+        present: "heute",
+        day_order: DayOrder::DayFirstWithPeriod,
+        years: ("J.", "J."),
+        months_unit: ("Mon.", "Mon."),
+        spaced_percent: true,
+    },
+];
+
+impl Locale {
+    /// The locale of a code such as "de-DE", or "de_DE.UTF-8" as in the environment.
+    pub fn parse(code: &str) -> Option<&'static Locale> {
+        let code = code.split('.').next().unwrap_or("").replace('_', "-");
+        LOCALES.iter().find(|locale| locale.code.eq_ignore_ascii_case(&code))
+    }
+
+    pub fn codes() -> Vec<&'static str> {
+        LOCALES.iter().map(|locale| locale.code).collect()
+    }
+
+    /// The locale dates are written with when only the language is chosen.
+    pub fn of(lang: Lang) -> &'static Locale {
+        LOCALES.iter().find(|locale| locale.lang == lang).unwrap_or(&LOCALES[0])
+    }
+
+    /// "Mar 2017", or "2017" when the month is not known.
+    pub fn date(&self, date: Date) -> String {
+        match date.month {
+            Some(month) => format!("{} {}", self.short_months[(month as usize - 1) % 12], date.year),
+            None => date.year.to_string(),
+        }
+    }
+
+    /// "Mar 2017 – present", or a single date for a period starting and ending the same month.
+    pub fn period(&self, period: Period) -> String {
+        match period.end {
+            Some(end) if end == period.start => self.date(period.start),
+            Some(end) => format!("{} – {}", self.date(period.start), self.date(end)),
+            None => format!("{} – {}", self.date(period.start), self.present),
+        }
+    }
+
+    /// A number of months as years and months, such as "2 yrs 3 mos".
+    pub fn duration(&self, months: i32) -> String {
+        let unit = |count: i32, (one, many): (&str, &str)| format!("{} {}", count, if count == 1 { one } else { many });
+        match (months / 12, months % 12) {
+            (0, months) => unit(months, self.months_unit),
+            (years, 0) => unit(years, self.years),
+            (years, months) => format!("{} {}", unit(years, self.years), unit(months, self.months_unit)),
+        }
+    }
+
+    /// A day written out in full, such as "October 18, 2026" or "18. Oktober 2026".
+    pub fn day(&self, date: Date, day: u32) -> String {
+        let month = self.months[(date.month.unwrap_or(1) as usize - 1) % 12];
+        match self.day_order {
+            DayOrder::MonthFirst => format!("{} {}, {}", month, day, date.year),
+            DayOrder::DayFirst => format!("{} {} {}", day, month, date.year),
+            DayOrder::DayFirstWithPeriod => format!("{}. {} {}", day, month, date.year),
+        }
+    }
+
+    /// "42%", or "42 %" where a space goes before the sign.
+    pub fn percent(&self, value: u16) -> String {
+        if self.spaced_percent {
+            format!("{}\u{a0}%", value)
+        } else {
+            format!("{}%", value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_are_written_in_years_and_months() {
+        let english = Locale::of(Lang::English);
+        assert_eq!(english.duration(0), "0 mos");
+        assert_eq!(english.duration(1), "1 mo");
+        assert_eq!(english.duration(11), "11 mos");
+        assert_eq!(english.duration(12), "1 yr");
+        assert_eq!(english.duration(13), "1 yr 1 mo");
+        assert_eq!(english.duration(27), "2 yrs 3 mos");
+        let german = Locale::of(Lang::German);
+        assert_eq!(german.duration(1), "1 Mon.");
+        assert_eq!(german.duration(27), "2 J. 3 Mon.");
+    }
+
+    #[test]
+    fn codes_are_read_as_in_the_environment() {
+        assert_eq!(Locale::parse("de_AT.UTF-8").map(|locale| locale.code), Some("de-AT"));
+        assert_eq!(Locale::parse("EN-gb").map(|locale| locale.code), Some("en-GB"));
+        assert!(Locale::parse("fr-FR").is_none());
+    }
+
+    #[test]
+    fn dates_follow_the_country() {
+        let march = Date { year: 2017, month: Some(3) };
+        let january = Date { year: 2017, month: Some(1) };
+        let austrian = Locale::parse("de-AT").unwrap();
+        assert_eq!(austrian.period(Period::new(january, Some(march))), "Jän. 2017 – März 2017");
+        assert_eq!(Locale::parse("en-CA").unwrap().period(Period::new(january, None)), "Jan. 2017 – present");
+        assert_eq!(Locale::parse("en-GB").unwrap().day(march, 18), "18 March 2017");
+        assert_eq!(Locale::parse("en-US").unwrap().day(march, 18), "March 18, 2017");
+        assert_eq!(austrian.day(march, 18), "18. März 2017");
+        assert_eq!(austrian.percent(42), "42\u{a0}%");
+    }
+}
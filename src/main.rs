@@ -1,7 +1,21 @@
 extern crate tui;
 extern crate termion;
+extern crate unicode_segmentation;
+extern crate unicode_width;
+extern crate serde;
+extern crate toml;
+extern crate flate2;
+extern crate serde_json;
 
+use std::cmp::Reverse;
+use std::env;
+use std::f64;
+use std::fs;
 use std::io;
+use std::iter;
+use std::ops::Range;
+use std::path::Path;
+use std::process;
 use std::thread;
 use std::time;
 use std::sync::mpsc;
@@ -10,10 +24,161 @@ use termion::event;
 use termion::input::TermRead;
 
 use tui::Terminal;
-use tui::backend::RawBackend;
-use tui::widgets::{Widget, Block, SelectableList, Gauge, Paragraph, Borders, Tabs};
+use tui::buffer::Buffer;
+use tui::widgets::{Widget, Block, SelectableList, Gauge, Paragraph, Borders, Tabs, BarChart, Table, Row};
+use tui::widgets::canvas::{Canvas, Context, Line};
 use tui::layout::{Group, Direction, Size, Rect};
-use tui::style::{Style, Color, Modifier};
+use tui::style::{Style, Color, Modifier, Alignment};
+
+use unicode_width::UnicodeWidthStr;
+
+mod backend;
+mod compare;
+mod data;
+mod date;
+mod diff;
+mod editor;
+mod git;
+mod history;
+mod i18n;
+mod import;
+mod letter;
+mod linear;
+mod locale;
+mod lint;
+mod markup;
+mod matching;
+mod query;
+mod resume;
+mod spelling;
+mod team;
+mod text;
+mod timeline;
+mod wizard;
+
+use backend::CvBackend;
+use compare::Comparison;
+use date::{Date, Period};
+use editor::{Action, Editor, STEPS};
+use history::History;
+use i18n::Lang;
+use letter::Format;
+use locale::Locale;
+use markup::Hyperlinks;
+use matching::{Found, Report};
+use query::Query;
+use resume::{Entry, Field, Job, Resume, Skill, Usage};
+use team::Team;
+use timeline::Timeline;
+use wizard::{Progress, Wizard};
+
+const USAGE: &str = "Usage: rust-cv [--ascii] [--linear] [--hyperlinks | --no-hyperlinks] [--no-animation] [--match FILE]
+               [--variant NAME] [--lang CODE] [--locale CODE] [--file FILE]
+       rust-cv match FILE
+       rust-cv cover-letter TEMPLATE JOB [--format markdown|text]
+       rust-cv lint [--dictionary PATH]...
+       rust-cv spellcheck [--dictionary PATH]...
+       rust-cv team DIR
+       rust-cv search DIR QUERY
+       rust-cv compare FILE FILE
+       rust-cv diff OLD NEW
+       rust-cv history FILE
+       rust-cv init FILE [EXPORT]
+
+Commands:
+    match FILE        Compare the resume with the job description in FILE and print a report
+    cover-letter TEMPLATE JOB
+                      Print a cover letter from the TEMPLATE file, for the position described in
+                      the JOB file with \"company: ...\", \"role: ...\" and \"keywords: ...\" lines
+    lint              List what applicant tracking systems could trip over in the resume
+    spellcheck        List the words of the resume that are not in the installed Hunspell
+                      dictionaries for English and German, or the small bundled ones without
+                      them. German compound words are not handled
+    team DIR          Pick a person from the resume files (*.toml) in DIR and show their resume
+    search DIR QUERY  Rank the people of the resume files in DIR by how well they match QUERY,
+                      such as \"skill:go>=60 lang:german location:berlin\", before picking one.
+                      QUERY also has role: and company:, and words looked for in names, roles
+                      and skills
+    compare FILE FILE Show the skills and the experience of two resume files side by side. Type d
+                      to see what changed from the first to the second
+    diff OLD NEW      Print what changed from the OLD resume file to the NEW one: jobs, dates,
+                      skill levels and the words of each paragraph
+    history FILE      Pick a commit that changed the resume FILE in its git repository, with
+                      what it changed, and show the resume as it was then
+    init FILE [EXPORT]
+                      Write a new resume FILE from a few questions: the name, contact details,
+                      languages, skills with their levels and jobs. The answers start from the
+                      EXPORT, if given: a JSON Resume file or a LinkedIn data export, as the zip
+                      archive or the folder it unzips to
+
+Options:
+    --ascii           Only draw ASCII characters, for consoles without Unicode support
+    --linear          Read the resume as plain text, one item at a time, for screen readers
+    --hyperlinks      Make links clickable, even if the terminal does not look like it supports it
+    --no-hyperlinks   Show the address of links instead of making them clickable
+    --no-animation    Draw charts in full right away, for slow connections
+    --file FILE       Show the resume of the data FILE instead of the built-in one. Type e in the
+                      application to edit the current section and save it to FILE, which is
+                      created from the built-in resume if it does not exist
+    --match FILE      Highlight what the resume has of the job description in FILE, with a report
+                      in a Match section
+    --variant NAME    Only show what the resume has for an audience, such as backend, devrel
+                      or writing, the most relevant first
+    --lang CODE       Show the resume in another language: en, the default, or de. Type l in the
+                      application to switch
+    --locale CODE     Write dates and numbers as in en-US, en-GB, en-CA, de-DE or de-AT. Defaults to
+                      en-US for English and de-DE for German, and sets the language when --lang
+                      is not given
+    --format FORMAT   Write the cover letter as markdown, the default, or text
+    --dictionary PATH Also check the spelling, and the typos of lint, with the Hunspell dictionary
+                      at PATH.aff and PATH.dic, such as /usr/share/hunspell/de_CH
+    -h, --help        Show this message";
+
+/// Index of the About section in the tabs, where contact details can be copied.
+const ABOUT: usize = 1;
+/// Index of the Skills section, where the skills can be sorted.
+const SKILLS: usize = 2;
+/// Index of the Experience section in the tabs, where jobs can be selected on the timeline.
+const EXPERIENCE: usize = 3;
+/// Index of the Match section in the tabs, only there with a job description.
+const MATCH: usize = 7;
+
+/// Milliseconds between two frames of the animations.
+const FRAME: u64 = 50;
+/// Milliseconds between two ticks when nothing is animated.
+const TICK: u64 = 200;
+/// Frames it takes for the charts of a section to fill up once it is shown.
+const TRANSITION_FRAMES: u32 = 10;
+/// Frames each line of the banner stays highlighted on the Home section.
+const BANNER_FRAMES: u32 = 3;
+
+/// Smallest terminal the sections are drawn in, anything smaller gets a notice instead.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+/// Below this width the side by side panes are stacked in a single column.
+const NARROW_WIDTH: u16 = 100;
+/// Below this height the borders and blank lines that are not essential are dropped.
+const SHORT_HEIGHT: u16 = 30;
+
+const BANNER_WIDTH: u16 = 47;
+const BANNER: [&str; 16] = [
+    r"-----------------------------------------------",
+    r"                   .'`. ,'`.",
+    r"             .---./    u    \,---.",
+    r"          ___|    \    |    /    |___",
+    r"         \    `.   \   |   /   .'    /",
+    r"          \_    `.  \  |  /  .'    _/",
+    r"        .-' `-._  `.:::::::.'  _.-' `-.",
+    r"        \       `-;:::::::::;-'       /",
+    r"         >~------~:::::::::::~------~<",
+    r"        /      _.-;:::::::::;-._      \",
+    r"        `-._.-'   .`.::::::'.   `-._.-'",
+    r"           /    .'  /  |  \  `.    \",
+    r"          /___.'   /   |   \   `.___\",
+    r"              |   /    |    \    |",
+    r"              `--'\   .n.   /`---'",
+    r"                   `.'   `.'",
+];
 
 pub struct MyTabs<'a> {
     pub titles: Vec<&'a str>,
@@ -32,11 +197,424 @@ impl<'a> MyTabs<'a> {
             self.selection = self.titles.len() - 1;
         }
     }
+
+    /// Columns needed to show every title, as laid out by the `Tabs` widget.
+    pub fn width(&self) -> u16 {
+        self.titles.iter().map(|title| title.width() as u16 + 3).sum()
+    }
+}
+
+/// How the programming languages are shown in the Skills section.
+#[derive(Clone, Copy, PartialEq)]
+enum SkillView {
+    Gauges,
+    Bars,
+    Radar,
+    Matrix,
+}
+
+impl SkillView {
+    fn next(self) -> SkillView {
+        match self {
+            SkillView::Gauges => SkillView::Bars,
+            SkillView::Bars => SkillView::Radar,
+            SkillView::Radar => SkillView::Matrix,
+            SkillView::Matrix => SkillView::Gauges,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SkillView::Gauges => "gauges",
+            SkillView::Bars => "bar chart",
+            SkillView::Radar => "radar chart",
+            SkillView::Matrix => "matrix",
+        }
+    }
+}
+
+/// Order of the programming languages in the Skills section.
+#[derive(Clone, Copy, PartialEq)]
+enum SkillOrder {
+    Listed,
+    Level,
+    Name,
+    Years,
+    LastUse,
+}
+
+impl SkillOrder {
+    fn next(self) -> SkillOrder {
+        match self {
+            SkillOrder::Listed => SkillOrder::Level,
+            SkillOrder::Level => SkillOrder::Name,
+            SkillOrder::Name => SkillOrder::Years,
+            SkillOrder::Years => SkillOrder::LastUse,
+            SkillOrder::LastUse => SkillOrder::Listed,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SkillOrder::Listed => "as listed",
+            SkillOrder::Level => "by level",
+            SkillOrder::Name => "by name",
+            SkillOrder::Years => "by years of use",
+            SkillOrder::LastUse => "by last use",
+        }
+    }
+}
+
+/// What to do, given as the first argument without dashes.
+#[derive(Default)]
+enum Command {
+    /// Show the resume, when there is no command
+    #[default]
+    Show,
+    /// Print how the resume matches a job description, from a file
+    Match(String),
+    /// Print a cover letter, from a template file and a job-info file
+    CoverLetter(String, String),
+    /// List the issues applicant tracking systems could have with the resume
+    Lint,
+    /// List the misspelled words of the resume
+    Spellcheck,
+    /// Show the resumes of the files of a directory, from a list of the people, with the query
+    /// the list is searched with at first
+    Team(String, String),
+    /// Show two resume files side by side
+    Compare(String, String),
+    /// Print the changes from a resume file to another
+    Diff(String, String),
+    /// Show the versions of a resume file from the commits of its git repository
+    History(String),
+    /// Write a new resume file from the answers to a few questions, starting from what
+    /// another tool exported, if given
+    Init(String, Option<String>),
+}
+
+/// Settings given on the command line.
+#[derive(Default)]
+struct Options {
+    command: Command,
+    ascii: bool,
+    linear: bool,
+    /// Output links as OSC 8 hyperlinks
+    hyperlinks: bool,
+    animation: bool,
+    /// Job description to match the resume against
+    matching: Option<String>,
+    /// Resume data file shown and edited instead of the built-in resume
+    file: Option<String>,
+    /// Audience the resume is narrowed down to
+    variant: Option<String>,
+    /// Format of the cover letter
+    format: Option<Format>,
+    /// Hunspell dictionaries to check the spelling with, besides the bundled ones
+    dictionaries: Vec<String>,
+    lang: Lang,
+    /// Conventions for dates and numbers, when not the ones of the language
+    locale: Option<&'static Locale>,
+}
+
+impl Options {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut hyperlinks = None;
+        let mut lang = None;
+        let mut positional = Vec::new();
+        options.animation = true;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--ascii" => options.ascii = true,
+                "--linear" => options.linear = true,
+                "--hyperlinks" => hyperlinks = Some(true),
+                "--no-hyperlinks" => hyperlinks = Some(false),
+                "--no-animation" => options.animation = false,
+                "--match" => options.matching = Some(args.next().ok_or("--match needs a file")?),
+                "--file" => options.file = Some(args.next().ok_or("--file needs a resume file")?),
+                "--variant" => options.variant = Some(args.next().ok_or("--variant needs a name")?),
+                "--lang" => {
+                    let code = args.next().ok_or("--lang needs a language")?;
+                    lang = Some(Lang::parse(&code).ok_or_else(|| format!("Unknown language: {}, use en or de", code))?);
+                }
+                "--locale" => {
+                    let code = args.next().ok_or("--locale needs a locale")?;
+                    let locale = Locale::parse(&code)
+                        .ok_or_else(|| format!("Unknown locale: {}, use one of {}", code, Locale::codes().join(", ")))?;
+                    options.locale = Some(locale);
+                }
+                "--dictionary" => options.dictionaries.push(args.next().ok_or("--dictionary needs a path")?),
+                "--format" => {
+                    let name = args.next().ok_or("--format needs a format")?;
+                    options.format = Some(Format::parse(&name).ok_or_else(|| format!("Unknown format: {}", name))?);
+                }
+                _ if arg.starts_with('-') => return Err(format!("Unknown argument: {}", arg)),
+                _ => positional.push(arg),
+            }
+        }
+        options.command = match positional.split_first() {
+            None => Command::Show,
+            Some((command, arguments)) => match (command.as_str(), arguments) {
+                ("match", [file]) => Command::Match(file.clone()),
+                ("match", _) => return Err(String::from("match needs a job description file")),
+                ("cover-letter", [template, job]) => Command::CoverLetter(template.clone(), job.clone()),
+                ("lint", []) => Command::Lint,
+                ("lint", _) => return Err(String::from("lint takes no arguments")),
+                ("spellcheck", []) => Command::Spellcheck,
+                ("spellcheck", _) => return Err(String::from("spellcheck takes no arguments")),
+                ("cover-letter", _) => return Err(String::from("cover-letter needs a template file and a job info file")),
+                ("team", [dir]) => Command::Team(dir.clone(), String::new()),
+                ("team", _) => return Err(String::from("team needs a directory of resume files")),
+                ("search", [dir, query @ ..]) if !query.is_empty() => {
+                    let query = query.join(" ");
+                    Query::parse(&query)?;
+                    Command::Team(dir.clone(), query)
+                }
+                ("search", _) => return Err(String::from("search needs a directory of resume files and a query")),
+                ("compare", [left, right]) => Command::Compare(left.clone(), right.clone()),
+                ("compare", _) => return Err(String::from("compare needs two resume files")),
+                ("diff", [old, new]) => Command::Diff(old.clone(), new.clone()),
+                ("diff", _) => return Err(String::from("diff needs an old and a new resume file")),
+                ("history", [file]) => Command::History(file.clone()),
+                ("history", _) => return Err(String::from("history needs a resume file in a git repository")),
+                ("init", [file]) => Command::Init(file.clone(), None),
+                ("init", [file, export]) => Command::Init(file.clone(), Some(export.clone())),
+                ("init", _) => return Err(String::from("init needs the resume file to write, and what to import, if anything")),
+                _ => return Err(format!("Unknown command: {}", command)),
+            },
+        };
+        options.hyperlinks = !options.ascii && hyperlinks.unwrap_or_else(backend::supports_hyperlinks);
+        options.lang = lang.or(options.locale.map(|locale| locale.lang)).unwrap_or_default();
+        Ok(options)
+    }
+
+    /// The locale dates are written with in a language: the one given, unless the language was
+    /// switched to another one than its own.
+    fn locale(&self, lang: Lang) -> &'static Locale {
+        match self.locale {
+            Some(locale) if locale.lang == lang || lang == self.lang => locale,
+            _ => Locale::of(lang),
+        }
+    }
 }
 
 struct App<'a> {
     size: Rect,
-    tabs: MyTabs<'a>
+    tabs: MyTabs<'a>,
+    options: Options,
+    /// The resume in the language it is written in
+    original: Resume,
+    /// The resume in the language shown
+    resume: Resume,
+    lang: Lang,
+    locale: &'static Locale,
+    /// Contact field that can be copied in the About section
+    contact: usize,
+    /// Whether that field has just been copied
+    copied: bool,
+    skill_view: SkillView,
+    skill_order: SkillOrder,
+    /// Job selected on the timeline of the Experience section
+    job: usize,
+    /// Where the jobs that are still going on end
+    today: Date,
+    /// Frames since the start, counted by the ticks
+    clock: u32,
+    /// Frame when what the current section shows changed
+    shown_at: u32,
+    /// The job description given with `--match`
+    description: Option<String>,
+    /// How the resume matches it
+    report: Option<Report>,
+    /// The people to pick the resume from, with `team`
+    team: Option<Team>,
+    /// The resumes shown side by side instead of the sections, if any
+    comparison: Option<Comparison>,
+    /// The versions to pick the resume from, with `history`
+    history: Option<History>,
+    /// The form the current section is edited with, if any
+    editor: Option<Editor>,
+    /// What happened when the last key was typed, such as the resume being saved
+    notice: Option<String>,
+    /// The steps of a new resume, with `init`, before it is shown
+    wizard: Option<Wizard>,
+}
+
+impl<'a> App<'a> {
+    /// Shows a resume picked from a list, from its Home section.
+    fn open(&mut self, person: Resume) {
+        self.report = self.description.as_ref().map(|text| matching::report(&person, text));
+        self.resume = person.translated(self.lang.code());
+        self.original = person;
+        self.tabs = MyTabs {
+            titles: titles(self.lang, self.report.is_some()),
+            selection: 0,
+        };
+        self.contact = 0;
+        self.copied = false;
+        self.job = 0;
+        self.show();
+    }
+
+    /// Whether the list of people is shown, rather than a resume.
+    fn listing(&self) -> bool {
+        self.team.as_ref().is_some_and(|team| !team.open)
+    }
+
+    /// Whether the list of versions is shown, rather than a resume.
+    fn browsing(&self) -> bool {
+        self.history.as_ref().is_some_and(|history| !history.open)
+    }
+
+    /// Whether the resume was picked from a list, which Esc goes back to.
+    fn picked(&self) -> bool {
+        self.team.is_some() || self.history.is_some()
+    }
+
+    /// Starts editing the current section, unless the resume cannot be saved.
+    fn edit(&mut self) {
+        let refusal = if self.picked() {
+            "Only the resume given with --file can be edited"
+        } else if self.options.file.is_none() {
+            "Start with --file FILE to edit the resume"
+        } else if self.options.variant.is_some() {
+            "The resume cannot be edited with --variant, which leaves parts of it out"
+        } else {
+            self.editor = Editor::new(self.original.clone(), self.tabs.selection);
+            if self.editor.is_some() {
+                return;
+            }
+            "This section cannot be edited"
+        };
+        self.notice = Some(String::from(self.lang.text(refusal)));
+    }
+
+    /// Saves what was typed in the editor to the file, showing the resume with it, or has the
+    /// editor show what is wrong.
+    fn save(&mut self) {
+        let (editor, path) = match (self.editor.as_mut(), self.options.file.as_ref()) {
+            (Some(editor), Some(path)) => (editor, path),
+            _ => return,
+        };
+        let resume = match editor.apply() {
+            Some(resume) => resume,
+            None => return,
+        };
+        if let Err(error) = data::save(&resume, Path::new(path)) {
+            editor.status = Some(error);
+            return;
+        }
+        self.notice = Some(self.lang.format("Saved to {}", &[path]));
+        self.editor = None;
+        self.report = self.description.as_ref().map(|text| matching::report(&resume, text));
+        self.resume = resume.translated(self.lang.code());
+        self.original = resume;
+        // The selected contact or job may have been removed
+        self.contact = self.contact.min(self.resume.contact.len().saturating_sub(1));
+        self.job = self.job.min(self.resume.experience.len().saturating_sub(1));
+        self.copied = false;
+        self.show();
+    }
+
+    /// Shows the next language, with the locale for it.
+    fn switch_lang(&mut self) {
+        self.lang = self.lang.next();
+        self.locale = self.options.locale(self.lang);
+        self.resume = self.original.translated(self.lang.code());
+        self.tabs.titles = titles(self.lang, self.report.is_some());
+    }
+
+    // Resumes from files may have no contact details or no jobs at all
+    fn next_contact(&mut self) {
+        self.contact = (self.contact + 1) % self.resume.contact.len().max(1);
+        self.copied = false;
+    }
+
+    fn previous_contact(&mut self) {
+        let count = self.resume.contact.len().max(1);
+        self.contact = (self.contact + count - 1) % count;
+        self.copied = false;
+    }
+
+    /// The programming languages with their usage, in the chosen order.
+    fn skills(&self) -> Vec<(&Skill, Usage)> {
+        let mut skills = self.resume.skills.iter()
+            .map(|skill| (skill, self.resume.usage(&skill.name, self.today)))
+            .collect::<Vec<(&Skill, Usage)>>();
+        // Most first, or alphabetically, ties keeping the order of the resume
+        match self.skill_order {
+            SkillOrder::Listed => {}
+            SkillOrder::Level => skills.sort_by_key(|&(skill, _)| Reverse(skill.level)),
+            SkillOrder::Name => skills.sort_by_key(|&(skill, _)| skill.name.to_lowercase()),
+            SkillOrder::Years => skills.sort_by_key(|(_, usage)| Reverse(usage.months)),
+            SkillOrder::LastUse => skills.sort_by_key(|(_, usage)| Reverse((usage.current, usage.last))),
+        }
+        skills
+    }
+
+    /// Restarts the animations of the current section, after it was changed.
+    fn show(&mut self) {
+        self.shown_at = self.clock;
+    }
+
+    /// How far the charts are drawn, from 0 when shown to 1, slowing down towards the end.
+    fn progress(&self) -> f64 {
+        if !self.options.animation {
+            return 1.0;
+        }
+        let elapsed = f64::from((self.clock - self.shown_at).min(TRANSITION_FRAMES)) / f64::from(TRANSITION_FRAMES);
+        1.0 - (1.0 - elapsed).powi(2)
+    }
+
+    fn next_job(&mut self) {
+        self.job = (self.job + 1) % self.resume.experience.len().max(1);
+    }
+
+    fn previous_job(&mut self) {
+        let count = self.resume.experience.len().max(1);
+        self.job = (self.job + count - 1) % count;
+    }
+
+    /// Turns resume text into `Paragraph` markup.
+    fn text(&self, text: &str) -> String {
+        markup::styled(text, self.options.hyperlinks)
+    }
+
+    /// The text of a paragraph in a bordered area, styled and broken into lines that fit.
+    fn paragraph(&self, text: &str, area: &Rect) -> String {
+        text::wrap(&self.text(text), area.width.saturating_sub(2))
+    }
+
+    /// Draws the text of a paragraph that was rendered in the given area, making its links
+    /// clickable when the terminal supports it.
+    fn draw_links(&self, t: &mut Terminal<CvBackend>, text: &str, area: &Rect) {
+        if self.options.hyperlinks {
+            let links = markup::links(text);
+            if !links.is_empty() {
+                Hyperlinks::new(&links).render(t, area);
+            }
+        }
+    }
+}
+
+/// Layout variant picked from the terminal size, `None` when it is too small to draw anything.
+#[derive(Clone, Copy)]
+struct Screen {
+    narrow: bool,
+    short: bool,
+}
+
+impl Screen {
+    fn new(size: &Rect) -> Option<Screen> {
+        if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+            return None;
+        }
+        Some(Screen {
+            narrow: size.width < NARROW_WIDTH,
+            short: size.height < SHORT_HEIGHT,
+        })
+    }
 }
 
 enum Event {
@@ -45,12 +623,201 @@ enum Event {
 }
 
 fn main() {
+    if env::args().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    let mut team = match options.command {
+        Command::Team(ref dir, ref query) => match data::load_dir(Path::new(dir)) {
+            Ok(people) => Some(Team::new(people, query, Date::today())),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => None,
+    };
+    let load = |path: &str| data::load(Path::new(path)).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let comparison = match options.command {
+        Command::Compare(ref left, ref right) => Some(Comparison::new([load(left), load(right)], [left, right])),
+        _ => None,
+    };
+    let mut history = match options.command {
+        Command::History(ref path) => match History::load(Path::new(path)) {
+            Ok(history) => Some(history),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => None,
+    };
+    if let Command::Diff(ref old, ref new) = options.command {
+        let (old, new) = (load(old), load(new));
+        let changes = diff::diff(&old, &new);
+        let paint = if backend::supports_color() { diff::ansi } else { diff::plain };
+        if !changes.is_empty() {
+            println!("{}", diff::report(&changes, options.lang, options.locale(options.lang), paint));
+        }
+        process::exit(if changes.is_empty() { 0 } else { 1 });
+    }
+    let wizard = match options.command {
+        Command::Init(ref path, ref export) => {
+            if options.file.is_some() || options.linear || options.variant.is_some() {
+                eprintln!("init cannot be used with --file, --linear and --variant");
+                process::exit(2);
+            }
+            if Path::new(path).exists() {
+                eprintln!("{} already exists, edit it with rust-cv --file {}", path, path);
+                process::exit(1);
+            }
+            let resume = match *export {
+                Some(ref export) => import::import(Path::new(export)).unwrap_or_else(|error| {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }),
+                None => Resume::default(),
+            };
+            Some(Wizard::new(path.clone(), resume))
+        }
+        _ => None,
+    };
+    let mut resume = Resume::builtin();
+    if let Some(ref path) = options.file {
+        // A file that does not exist yet is written from the built-in resume once edited
+        if Path::new(path).exists() {
+            resume = load(path);
+        }
+    }
+    if let Some(ref variant) = options.variant {
+        // People and versions without anything for the audience are shown in full
+        let narrow = |person: Resume| if person.audiences().contains(&variant.as_str()) { person.variant(variant) } else { person };
+        match (&mut team, &mut history) {
+            (Some(team), _) => {
+                team.people = team.people.drain(..).map(narrow).collect();
+            }
+            (_, Some(history)) => {
+                history.current = narrow(history.current.clone());
+                for version in &mut history.versions {
+                    if let Ok(ref resume) = version.resume {
+                        version.resume = Ok(narrow(resume.clone()));
+                    }
+                }
+            }
+            (None, None) => {
+                if !resume.audiences().contains(&variant.as_str()) {
+                    eprintln!("Unknown variant: {}, the resume has {}", variant, resume.audiences().join(", "));
+                    process::exit(2);
+                }
+                resume = resume.variant(variant);
+            }
+        }
+    }
+    if let Command::Match(ref path) = options.command {
+        let report = matching::report(&resume, &read(path));
+        print!("{}", matching::print(&resume, &report, options.lang, options.locale(options.lang)));
+        return;
+    }
+    if let Command::CoverLetter(ref template, ref job) = options.command {
+        let format = options.format.unwrap_or(Format::Markdown);
+        let (today, day) = Date::today_with_day();
+        let locale = options.locale(options.lang);
+        match letter::write(&resume.translated(options.lang.code()), &read(template), &read(job), format, locale, today, day) {
+            Ok(letter) => print!("{}", letter),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+    if let Command::Lint | Command::Spellcheck = options.command {
+        let mut paths = spelling::installed();
+        if paths.is_empty() && matches!(options.command, Command::Spellcheck) {
+            eprintln!("No Hunspell dictionary is installed, the bundled German list is short and many right German words \
+                       are reported: install hunspell-de-de, or give a dictionary with --dictionary");
+        }
+        paths.extend(options.dictionaries.iter().cloned());
+        let checker = match spelling::Checker::new(&paths) {
+            Ok(checker) => checker,
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(2);
+            }
+        };
+        let warnings = if let Command::Lint = options.command {
+            lint::lint(&resume, options.locale(options.lang), &checker)
+        } else {
+            spelling::spellcheck(&resume, &checker)
+        };
+        for warning in &warnings {
+            println!("{}", warning);
+        }
+        println!("{} warning{}", warnings.len(), if warnings.len() == 1 { "" } else { "s" });
+        if !warnings.is_empty() {
+            process::exit(1);
+        }
+        return;
+    }
+    if options.file.is_some() && (team.is_some() || comparison.is_some() || history.is_some()) {
+        eprintln!("--file cannot be used with team, search, compare and history, which show their own files");
+        process::exit(2);
+    }
+    if options.linear && (team.is_some() || comparison.is_some() || history.is_some()) {
+        eprintln!("team, search, compare and history cannot be read with --linear, run it on one of the files instead");
+        process::exit(2);
+    }
+    if options.linear {
+        linear::run(&resume.translated(options.lang.code()), options.lang, options.locale(options.lang)).unwrap();
+        return;
+    }
+    if let Some(ref team) = team {
+        resume = team.people[0].clone();
+    }
+    if let Some(ref history) = history {
+        resume = history.current.clone();
+    }
+    let description = options.matching.as_ref().map(|path| read(path));
+    let report = description.as_ref().map(|text| matching::report(&resume, text));
+    let lang = options.lang;
+    let locale = options.locale(lang);
     let mut app = App {
         size: Rect::default(),
         tabs: MyTabs {
-            titles: vec!["Home", "About", "Skills", "Experience", "Education", "Projects", "Objective"],
+            titles: titles(lang, report.is_some()),
             selection: 0,
-        }
+        },
+        options,
+        resume: resume.translated(lang.code()),
+        original: resume,
+        lang,
+        locale,
+        contact: 0,
+        copied: false,
+        skill_view: SkillView::Gauges,
+        skill_order: SkillOrder::Listed,
+        job: 0,
+        today: Date::today(),
+        clock: 0,
+        shown_at: 0,
+        description,
+        report,
+        team,
+        comparison,
+        history,
+        editor: None,
+        notice: None,
+        wizard,
     };
     let (tx, rx) = mpsc::channel();
     let input_tx = tx.clone();
@@ -58,23 +825,20 @@ fn main() {
     thread::spawn(move || {
         let stdin = io::stdin();
         for c in stdin.keys() {
-            let evt = c.unwrap();
-            input_tx.send(Event::Input(evt)).unwrap();
-            if evt == event::Key::Char('q') {
-                break;
-            }
+            input_tx.send(Event::Input(c.unwrap())).unwrap();
         }
     });
 
+    let tick = if app.options.animation { FRAME } else { TICK };
     thread::spawn(move || {
         let tx = tx.clone();
         loop {
             tx.send(Event::Tick).unwrap();
-            thread::sleep(time::Duration::from_millis(200));
+            thread::sleep(time::Duration::from_millis(tick));
         }
     });
 
-    let backend = RawBackend::new().unwrap();
+    let backend = CvBackend::new(app.options.ascii).unwrap();
     let mut terminal = Terminal::new(backend).unwrap();
     terminal.clear().unwrap();
     terminal.hide_cursor().unwrap();
@@ -88,580 +852,1618 @@ fn main() {
         draw(&mut terminal, &app).unwrap();
         let evt = rx.recv().unwrap();
         match evt {
+            Event::Input(input) if app.wizard.is_some() => {
+                let progress = app.wizard.as_mut().map_or(Progress::Going, |wizard| wizard.key(input));
+                match progress {
+                    Progress::Done(resume) => {
+                        // The new file is shown, and edited, as with --file
+                        if let Some(wizard) = app.wizard.take() {
+                            app.notice = Some(app.lang.format("Saved to {}", &[&wizard.path]));
+                            app.options.file = Some(wizard.path);
+                        }
+                        app.open(*resume);
+                    }
+                    Progress::Abandoned => break,
+                    Progress::Going => {}
+                }
+            }
+            Event::Input(input) if app.editor.is_some() => {
+                let action = app.editor.as_mut().map_or(Action::Nothing, |editor| editor.key(input));
+                match action {
+                    Action::Save => app.save(),
+                    Action::Cancel => app.editor = None,
+                    Action::Nothing => {}
+                }
+            }
+            Event::Input(input) if app.comparison.is_some() => {
+                match input {
+                    event::Key::Char('q') => {
+                        break;
+                    }
+                    event::Key::Char('l') => {
+                        app.switch_lang();
+                    }
+                    event::Key::Left | event::Key::Right => {
+                        if let Some(ref mut comparison) = app.comparison {
+                            comparison.experience = !comparison.experience;
+                        }
+                        app.show();
+                    }
+                    event::Key::Char('d') => {
+                        if let Some(ref mut comparison) = app.comparison {
+                            comparison.diff = !comparison.diff;
+                            comparison.scroll = 0;
+                        }
+                    }
+                    event::Key::Up | event::Key::Down if app.comparison.as_ref().is_some_and(|comparison| comparison.diff) => {
+                        if let Some(ref mut comparison) = app.comparison {
+                            let [ref old, ref new] = comparison.people;
+                            let lines = diff::report(&diff::diff(old, new), app.lang, app.locale, diff::plain).lines().count() as u16;
+                            comparison.scroll = if input == event::Key::Up {
+                                comparison.scroll.saturating_sub(1)
+                            } else {
+                                (comparison.scroll + 1).min(lines.saturating_sub(1))
+                            };
+                        }
+                    }
+                    event::Key::Esc if app.comparison.as_ref().is_some_and(|comparison| comparison.diff) => {
+                        if let Some(ref mut comparison) = app.comparison {
+                            comparison.diff = false;
+                        }
+                    }
+                    event::Key::Esc if app.picked() => {
+                        app.comparison = None;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Input(input) if app.listing() => {
+                let mut open = false;
+                let mut compare = None;
+                let mut switch = false;
+                if let Some(ref mut team) = app.team {
+                    match input {
+                        event::Key::Up => team.previous(),
+                        event::Key::Down => team.next(),
+                        event::Key::Char('\n') => open = true,
+                        event::Key::Esc if team.searching => {
+                            team.searching = false;
+                            team.clear();
+                        }
+                        event::Key::Backspace if team.searching => team.erase(),
+                        event::Key::Char(c) if team.searching => team.type_char(c),
+                        event::Key::Char('/') => team.searching = true,
+                        event::Key::Char('c') => match (team.marked.take(), team.selection()) {
+                            (Some(marked), Some(selected)) => compare = Some([marked, selected.clone()]),
+                            (None, Some(selected)) => team.marked = Some(selected.clone()),
+                            _ => {}
+                        },
+                        event::Key::Esc if team.marked.is_some() => team.marked = None,
+                        event::Key::Esc => team.clear(),
+                        event::Key::Char('l') => switch = true,
+                        event::Key::Char('q') => break,
+                        _ => {}
+                    }
+                }
+                if open {
+                    if let Some(person) = app.team.as_ref().and_then(Team::selection).cloned() {
+                        app.open(person);
+                        if let Some(ref mut team) = app.team {
+                            team.open = true;
+                        }
+                    }
+                }
+                if let Some(people) = compare {
+                    let names = [people[0].name.clone(), people[1].name.clone()];
+                    app.comparison = Some(Comparison::new(people, [&names[0], &names[1]]));
+                    app.show();
+                }
+                if switch {
+                    app.switch_lang();
+                }
+            }
+            Event::Input(input) if app.browsing() => {
+                let mut open = None;
+                let mut compare = None;
+                let mut switch = false;
+                if let Some(ref mut history) = app.history {
+                    match input {
+                        event::Key::Up => history.previous(),
+                        event::Key::Down => history.next(),
+                        event::Key::Char('\n') => {
+                            if let Ok(ref resume) = history.selection().resume {
+                                open = Some(resume.clone());
+                                history.open = true;
+                            }
+                        }
+                        event::Key::Char('c') => {
+                            let version = history.selection();
+                            if let Ok(ref resume) = version.resume {
+                                let (date, day) = version.date();
+                                let title = format!("{} ({})", version.commit.id.short(), app.locale.day(date, day));
+                                compare = Some(([resume.clone(), history.current.clone()], title));
+                            }
+                        }
+                        event::Key::Char('l') => switch = true,
+                        event::Key::Char('q') => break,
+                        _ => {}
+                    }
+                }
+                if let Some(resume) = open {
+                    app.open(resume);
+                }
+                if let Some((people, title)) = compare {
+                    let now = app.lang.text("Now");
+                    app.comparison = Some(Comparison::new(people, [&title, now]));
+                    app.show();
+                }
+                if switch {
+                    app.switch_lang();
+                }
+            }
             Event::Input(input) => {
+                app.notice = None;
                 match input {
                     event::Key::Char('q') => {
                         break;
                     }
+                    event::Key::Char('l') => {
+                        app.switch_lang();
+                    }
+                    event::Key::Char('e') => {
+                        app.edit();
+                    }
+                    event::Key::Esc if app.picked() => {
+                        if let Some(ref mut team) = app.team {
+                            team.open = false;
+                        }
+                        if let Some(ref mut history) = app.history {
+                            history.open = false;
+                        }
+                    }
                     event::Key::Left => {
                         app.tabs.previous();
+                        app.show();
                     }
                     event::Key::Right => {
                         app.tabs.next();
+                        app.show();
+                    }
+                    event::Key::Up if app.tabs.selection == ABOUT => {
+                        app.previous_contact();
+                    }
+                    event::Key::Down if app.tabs.selection == ABOUT => {
+                        app.next_contact();
+                    }
+                    event::Key::Char('v') if app.tabs.selection == SKILLS => {
+                        app.skill_view = app.skill_view.next();
+                        app.show();
+                    }
+                    event::Key::Char('s') if app.tabs.selection == SKILLS => {
+                        app.skill_order = app.skill_order.next();
+                    }
+                    event::Key::Up if app.tabs.selection == EXPERIENCE => {
+                        app.previous_job();
+                    }
+                    event::Key::Down if app.tabs.selection == EXPERIENCE => {
+                        app.next_job();
+                    }
+                    event::Key::Char('y') if app.tabs.selection == ABOUT && !app.resume.contact.is_empty() => {
+                        let text = markup::copyable(&app.resume.contact[app.contact].value);
+                        terminal.backend_mut().copy(&text).unwrap();
+                        app.copied = true;
                     }
                     _ => {}
                 }
             }
-            Event::Tick => {}
+            Event::Tick => {
+                app.clock += 1;
+            }
         }
     }
     terminal.show_cursor().unwrap();
     terminal.clear().unwrap();
 }
 
-fn draw(t: &mut Terminal<RawBackend>, app: &App) -> Result<(), io::Error> {
+/// Titles of the sections, in the language shown, with the Match one when there is a report.
+fn titles(lang: Lang, matching: bool) -> Vec<&'static str> {
+    let mut titles = vec!["Home", "About", "Skills", "Experience", "Education", "Projects", "Objective"];
+    if matching {
+        titles.push("Match");
+    }
+    titles.into_iter().map(|title| lang.text(title)).collect()
+}
+
+/// Contents of a file given on the command line, exiting when it cannot be read.
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("Cannot read {}: {}", path, error);
+        process::exit(1);
+    })
+}
+
+fn draw(t: &mut Terminal<CvBackend>, app: &App) -> Result<(), io::Error> {
+    match Screen::new(&app.size) {
+        Some(screen) if app.wizard.is_some() => draw_wizard(t, app, screen),
+        Some(screen) if app.comparison.is_some() => draw_comparison(t, app, screen),
+        Some(screen) if app.listing() => draw_team(t, app, screen),
+        Some(screen) if app.browsing() => draw_history(t, app, screen),
+        Some(screen) => draw_sections(t, app, screen),
+        None => draw_too_small(t, app.lang, &app.size),
+    }
+    t.draw()?;
+    Ok(())
+}
 
+fn draw_sections(t: &mut Terminal<CvBackend>, app: &App, screen: Screen) {
+    let header = if screen.short { 1 } else { 3 };
     Group::default()
         .direction(Direction::Vertical)
-        .sizes(&[Size::Fixed(3), Size::Min(0)])
+        .sizes(&[Size::Fixed(header), Size::Min(0)])
         .render(t, &app.size, |t, chunks| {
-            Tabs::default()
-                .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Sections").title_style(Style::default().fg(Color::Cyan)))
-                .titles(&app.tabs.titles)
-                .style(Style::default().fg(Color::Magenta))
-                .highlight_style(Style::default().fg(Color::Yellow))
-                .select(app.tabs.selection)
-                .render(t, &chunks[0]);
+            draw_tabs(t, app, screen, &chunks[0]);
+            if let Some(ref editor) = app.editor {
+                let title = app.lang.format("Edit {}", &[&app.tabs.titles[app.tabs.selection]]);
+                let hint = app.lang.text("Tab next, Enter new line, Ctrl+N add an item, Ctrl+S save, Esc cancel");
+                draw_editor(t, app, editor, &title, hint, &chunks[1]);
+                return;
+            }
             match app.tabs.selection {
                 0 => {
-                    draw_home(t, &chunks[1]);
+                    draw_home(t, app, screen, &chunks[1]);
                 }
                 1 => {
-                    draw_about(t, &chunks[1]);
+                    draw_about(t, app, screen, &chunks[1]);
                 }
                 2 => {
-                    draw_skills(t, &chunks[1]);
+                    draw_skills(t, app, screen, &chunks[1]);
                 }
                 3 => {
-                    draw_experience(t, &chunks[1]);
+                    draw_experience(t, app, screen, &chunks[1]);
                 }
                 4 => {
-                    draw_education(t, &chunks[1]);
+                    draw_education(t, app, &chunks[1]);
                 }
                 5 => {
-                    draw_projects(t, &chunks[1]);
+                    draw_projects(t, app, &chunks[1]);
                 }
                 6 => {
-                    draw_objective(t, &chunks[1]);
+                    draw_objective(t, app, screen, &chunks[1]);
+                }
+                MATCH => {
+                    draw_match(t, app, screen, &chunks[1]);
                 }
                 _ => {}
             };
         });
-    t.draw()?;
-    Ok(())
 }
 
-fn draw_home(t: &mut Terminal<RawBackend>, area: &Rect) {
+fn draw_team(t: &mut Terminal<CvBackend>, app: &App, screen: Screen) {
+    let team = match app.team {
+        Some(ref team) => team,
+        None => return,
+    };
+    let listed = team.listed();
+    let sizes = if screen.narrow { [Size::Percent(100), Size::Percent(0)] } else { [Size::Percent(60), Size::Percent(40)] };
     Group::default()
-        .direction(Direction::Vertical)
-        .sizes(&[Size::Percent(10), Size::Percent(80), Size::Percent(10)])
-        .render(t, area, |t, chunks| {
-                Group::default()
-                .direction(Direction::Horizontal)
-                .sizes(&[Size::Percent(10), Size::Percent(80), Size::Percent(10)])
-                .render(t, &chunks[1], |t, chunks| {
+        .direction(Direction::Horizontal)
+        .sizes(&sizes)
+        .render(t, &app.size, |t, chunks| {
+            let title = app.lang.format("People ({} of {})", &[&listed.len(), &team.people.len()]);
+            let mut block = Block::default()
+                .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                .title(&title)
+                .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold));
+            block.render(t, &chunks[0]);
+            let inner = block.inner(&chunks[0]);
+            let mut search = if team.searching || !team.query.is_empty() {
+                let cursor = if team.searching { "{mod=invert  }" } else { "" };
+                app.lang.format("{mod=bold;fg=yellow Search:} {}{} - {mod=bold;fg=yellow Esc} clear",
+                                &[&markup::escape(&team.query), &cursor])
+            } else if let Some(ref marked) = team.marked {
+                app.lang.format("{mod=bold;fg=yellow c} compares with {}, {mod=bold;fg=yellow Esc} cancels", &[&markup::escape(&marked.name)])
+            } else {
+                String::from(app.lang.text("{mod=bold;fg=yellow /} search, {mod=bold;fg=yellow ↑↓} select, \
+                                            {mod=bold;fg=yellow Enter} open, {mod=bold;fg=yellow c} compare, \
+                                            {mod=bold;fg=yellow q} exit"))
+            };
+            if let Some(error) = team.error() {
+                search.push_str(&format!("\n{{fg=red {}}}", markup::escape(&error)));
+            }
+            Group::default()
+                .direction(Direction::Vertical)
+                .sizes(&[Size::Fixed(3), Size::Min(0)])
+                .render(t, &inner, |t, chunks| {
                     Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("DAISY T'S RESUME")
-                        .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nUse {mod=bold;fg=yellow ←}  and {mod=bold;fg=yellow →}  to navigate between the sections.\n\n\
-                           Type {mod=bold;fg=yellow q} to exit the application.\n\n\
-                                                    -----------------------------------------------
-                                                                    .'`. ,'`.
-                                                              .---./    u    \\,---.
-                                                           ___|    \\    |    /    |___
-                                                          \\    `.   \\   |   /   .'    /
-                                                           \\_    `.  \\  |  /  .'    _/
-                                                         .-' `-._  `.:::::::.'  _.-' `-.
-                                                         \\       `-;:::::::::;-'       /
-                                                          >~------~:::::::::::~------~<
-                                                         /      _.-;:::::::::;-._      \\
-                                                         `-._.-'   .`.::::::'.   `-._.-'
-                                                            /    .'  /  |  \\  `.    \\
-                                                           /___.'   /   |   \\   `.___\\
-                                                               |   /    |    \\    |
-                                                               `--'\\   .n.   /`---'
-                                                                    `.'   `.'          
-
-                           ")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[1]);
+                        .wrap(true)
+                        .text(&search)
+                        .style(Style::default().fg(Color::LightMagenta))
+                        .render(t, &chunks[0]);
+                    // With a query, people are ranked by how well they match it
+                    let ranked = !team.query.trim().is_empty();
+                    let score = if ranked { app.lang.text("Match").width().max(app.locale.percent(100).width()) as u16 } else { 0 };
+                    // Name and role get what they need, up to a third of the width each
+                    let third = chunks[1].width.saturating_sub(score) / 3;
+                    let name = listed.iter().map(|&(person, _)| person.name.width()).max().unwrap_or(0) as u16;
+                    let role = listed.iter().map(|&(person, _)| team::role(person).width()).max().unwrap_or(0) as u16;
+                    let name = name.max(app.lang.text("Name").width() as u16).min(third);
+                    let role = role.max(app.lang.text("Role").width() as u16).min(third);
+                    let mut widths = vec![name, role, chunks[1].width.saturating_sub(score + name + role + 3)];
+                    let mut header = vec![app.lang.text("Name"), app.lang.text("Role"), app.lang.text("Top skills")];
+                    if ranked {
+                        widths.insert(0, score);
+                        header.insert(0, app.lang.text("Match"));
+                    }
+                    // Rows scroll so that the selected person stays in view, below the header
+                    let rows = chunks[1].height.saturating_sub(2) as usize;
+                    let first = (team.selected + 1).saturating_sub(rows);
+                    let cells = listed.iter()
+                        .map(|&(person, score)| {
+                            let mut row = vec![text::cells(&person.name), text::cells(team::role(person)),
+                                               text::cells(&team::top_skills(person).join(", "))];
+                            if ranked {
+                                row.insert(0, app.locale.percent(score));
+                            }
+                            row
+                        })
+                        .collect::<Vec<Vec<String>>>();
+                    let selected = Style::default().fg(Color::Yellow).modifier(Modifier::Invert);
+                    Table::new(header.iter(), cells.iter().enumerate().skip(first).map(|(i, row)| {
+                        if i == team.selected { Row::StyledData(row.iter(), &selected) } else { Row::Data(row.iter()) }
+                    }))
+                        .header_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold))
+                        .widths(&widths)
+                        .style(Style::default().fg(Color::LightMagenta))
+                        .render(t, &chunks[1]);
                 });
+            if !screen.narrow {
+                draw_person(t, app, team.selection(), &chunks[1]);
+            }
         });
 }
 
-fn draw_about(t: &mut Terminal<RawBackend>, area: &Rect) {
+/// A glance at the resume of the person selected in the team list.
+fn draw_person(t: &mut Terminal<CvBackend>, app: &App, person: Option<&Resume>, area: &Rect) {
+    let mut block = Block::default()
+        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+        .title(app.lang.text("About"))
+        .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold));
+    let person = match person {
+        Some(person) => person.translated(app.lang.code()),
+        None => {
+            block.render(t, area);
+            return;
+        }
+    };
+    let mut text = format!("{{mod=bold;fg=yellow {}}}\n", markup::escape(&person.name));
+    if let Some(job) = person.current_job() {
+        text.push_str(&format!("{}, {}\n", markup::escape(&job.role), markup::escape(&job.company)));
+    }
+    text.push('\n');
+    text.push_str(&fields(app, &person.information, Screen { narrow: true, short: true }, None));
+    let languages = person.languages.iter().map(|field| field.label.as_str()).collect::<Vec<&str>>();
+    if !languages.is_empty() {
+        text.push_str(&format!("{{mod=bold;fg=yellow {}:}} {}\n", app.lang.text("Languages"), markup::escape(&languages.join(", "))));
+    }
+    text.push_str(&app.text(&description(&person.about)));
+    Paragraph::default()
+        .block(block)
+        .wrap(true)
+        .text(&text::wrap(&text, area.width.saturating_sub(2)))
+        .style(Style::default().fg(Color::LightMagenta))
+        .render(t, area);
+}
+
+fn draw_history(t: &mut Terminal<CvBackend>, app: &App, screen: Screen) {
+    let history = match app.history {
+        Some(ref history) => history,
+        None => return,
+    };
+    let sizes = if screen.narrow { [Size::Percent(100), Size::Percent(0)] } else { [Size::Percent(60), Size::Percent(40)] };
     Group::default()
-        .direction(Direction::Vertical)
-        .sizes(&[Size::Percent(40), Size::Percent(60)])
-        .render(t, area, |t, chunks| {
-            Group::default()
-            .direction(Direction::Horizontal)
-            .sizes(&[Size::Percent(50), Size::Percent(50)])
-            .render(t, &chunks[0], |t, chunks| {
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Information")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\n{mod=bold;fg=yellow Name:} Daisy T\n\n\
-                       {mod=bold;fg=yellow Nationality:} Canadian\n\n\
-                       {mod=bold;fg=yellow Currently based in:} Berlin, Germany\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[0]);
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Languages")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\n{mod=bold;fg=yellow English:} Native\n\n\
-                       {mod=bold;fg=yellow French:} Good Knowledge\n\n\
-                       {mod=bold;fg=yellow German:} Good Knowledge\n\n\
-                       {mod=bold;fg=yellow Cantonese:} Conversational\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[1]);
-            });
+        .direction(Direction::Horizontal)
+        .sizes(&sizes)
+        .render(t, &app.size, |t, chunks| {
+            let title = app.lang.format("History of {}", &[&history.file]);
+            let mut block = Block::default()
+                .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                .title(&title)
+                .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold));
+            block.render(t, &chunks[0]);
+            let inner = block.inner(&chunks[0]);
+            let help = app.lang.text("{mod=bold;fg=yellow ↑↓} select, {mod=bold;fg=yellow Enter} open, \
+                                      {mod=bold;fg=yellow c} compare with now, {mod=bold;fg=yellow q} exit");
             Group::default()
-            .direction(Direction::Horizontal)
-            .sizes(&[Size::Percent(50), Size::Percent(50)])
-            .render(t, &chunks[1], |t, chunks| {
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Contact")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\n{mod=bold;fg=yellow Email:} daisyts@gmx.com\n\n\
-                       {mod=bold;fg=yellow Phone:} +49 (0) 176 3163 5400\n\n\
-                       {mod=bold;fg=yellow Website:} https://infoverload.ca/\n\n\
-                       {mod=bold;fg=yellow Twitter:} https://twitter.com/1nfoverload\n\n\
-                       {mod=bold;fg=yellow LinkedIn:} http://linkedin.com/in/daisyts\n\n\
-                       {mod=bold;fg=yellow LinkedIn:} https://github.com/infoverload\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
+                .direction(Direction::Vertical)
+                .sizes(&[Size::Fixed(2), Size::Min(0)])
+                .render(t, &inner, |t, chunks| {
+                    Paragraph::default()
+                        .wrap(true)
+                        .text(help)
+                        .style(Style::default().fg(Color::LightMagenta))
+                        .render(t, &chunks[0]);
+                    let cells = history.versions.iter()
+                        .map(|version| {
+                            let (date, day) = version.date();
+                            vec![app.locale.day(date, day), version.commit.id.short(), text::cells(&version.commit.summary)]
+                        })
+                        .collect::<Vec<Vec<String>>>();
+                    let header = [app.lang.text("Date"), app.lang.text("Commit"), app.lang.text("Message")];
+                    let date = cells.iter().map(|row| row[0].width()).max().unwrap_or(0).max(header[0].width()) as u16;
+                    let commit = header[1].width().max(7) as u16;
+                    let widths = [date, commit, chunks[1].width.saturating_sub(date + commit + 2)];
+                    // Rows scroll so that the selected version stays in view, below the header
+                    let rows = chunks[1].height.saturating_sub(2) as usize;
+                    let first = (history.selected + 1).saturating_sub(rows);
+                    let selected = Style::default().fg(Color::Yellow).modifier(Modifier::Invert);
+                    let unreadable = Style::default().fg(Color::Red);
+                    Table::new(header.iter(), cells.iter().enumerate().skip(first).map(|(i, row)| {
+                        if i == history.selected {
+                            Row::StyledData(row.iter(), &selected)
+                        } else if history.versions[i].resume.is_err() {
+                            Row::StyledData(row.iter(), &unreadable)
+                        } else {
+                            Row::Data(row.iter())
+                        }
+                    }))
+                        .header_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold))
+                        .widths(&widths)
+                        .style(Style::default().fg(Color::LightMagenta))
+                        .render(t, &chunks[1]);
+                });
+            if !screen.narrow {
+                draw_version(t, app, history, &chunks[1]);
+            }
+        });
+}
+
+/// What the version selected in the history changed, from the version before it.
+fn draw_version(t: &mut Terminal<CvBackend>, app: &App, history: &History, area: &Rect) {
+    let version = history.selection();
+    let (date, day) = version.date();
+    let mut text = format!("{{mod=bold;fg=yellow {}}}, {}\n\n", markup::escape(&version.commit.author), app.locale.day(date, day));
+    let changes = match (&version.resume, history.previous_version()) {
+        (Err(error), _) => markup::escape(error),
+        (Ok(resume), Some(previous)) => {
+            let (old, new) = (previous.translated(app.lang.code()), resume.translated(app.lang.code()));
+            let changes = diff::diff(&old, &new);
+            if changes.is_empty() {
+                markup::escape(app.lang.text("No changes"))
+            } else {
+                diff::report(&changes, app.lang, app.locale, diff::styled)
+            }
+        }
+        (Ok(_), None) => markup::escape(app.lang.text("First version")),
+    };
+    text.push_str(&changes);
+    Paragraph::default()
+        .block(Block::default()
+            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+            .title(app.lang.text("Changes"))
+            .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
+        .text(&text::wrap(&text, area.width.saturating_sub(2)))
+        .style(Style::default().fg(Color::LightMagenta))
+        .render(t, area);
+}
+
+/// Colors of the left and the right side of a comparison.
+const SIDES: [(Color, &str); 2] = [(Color::Magenta, "magenta"), (Color::Cyan, "cyan")];
+
+/// Two resumes side by side, their skills or their experience.
+fn draw_comparison(t: &mut Terminal<CvBackend>, app: &App, screen: Screen) {
+    let comparison = match app.comparison {
+        Some(ref comparison) => comparison,
+        None => return,
+    };
+    let people = [comparison.people[0].translated(app.lang.code()), comparison.people[1].translated(app.lang.code())];
+    let compared = Comparison {
+        people,
+        titles: comparison.titles.clone(),
+        ..*comparison
+    };
+    let header = if screen.short { 1 } else { 3 };
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Fixed(header), Size::Fixed(1), Size::Min(0)])
+        .render(t, &app.size, |t, chunks| {
+            let title = app.lang.text(if app.picked() { "Compare - ←→ section, d changes, Esc back" } else { "Compare - ←→ section, d changes" });
+            let titles = [app.lang.text("Skills"), app.lang.text("Experience")];
+            let mut tabs = Tabs::default();
+            if !screen.short {
+                tabs.block(Block::default()
+                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                    .title(title)
+                    .title_style(Style::default().fg(Color::Cyan)));
+            }
+            tabs.titles(&titles)
+                .style(Style::default().fg(Color::Magenta))
+                .highlight_style(Style::default().fg(Color::Yellow))
+                .select(if compared.experience { 1 } else { 0 })
                 .render(t, &chunks[0]);
+            Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .render(t, &chunks[1], |t, halves| {
+                    for ((title, &(_, color)), half) in compared.titles.iter().zip(&SIDES).zip(halves) {
+                        Paragraph::default()
+                            .text(&format!("{{mod=bold;fg={} {}}}", color, markup::escape(&text::cells(title))))
+                            .render(t, half);
+                    }
+                });
+            if compared.experience {
+                draw_compared_experience(t, app, &compared, &chunks[2]);
+            } else {
+                draw_compared_skills(t, app, screen, &compared, &chunks[2]);
+            }
+        });
+    if compared.diff {
+        draw_changes(t, app, &compared);
+    }
+}
+
+/// Empties an area, symbols included, so that what is drawn over something else does not
+/// show it through the gaps.
+struct Clear;
+
+impl Widget for Clear {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf.get_mut(x, y).reset();
+            }
+        }
+    }
+}
+
+/// What changed from the left side to the right one, in a box over the comparison.
+fn draw_changes(t: &mut Terminal<CvBackend>, app: &App, comparison: &Comparison) {
+    let size = &app.size;
+    let (width, height) = (size.width * 4 / 5, size.height * 4 / 5);
+    let area = Rect::new(size.x + (size.width - width) / 2, size.y + (size.height - height) / 2, width, height);
+    let [ref old, ref new] = comparison.people;
+    let changes = diff::diff(old, new);
+    let report = if changes.is_empty() {
+        markup::escape(app.lang.text("No changes"))
+    } else {
+        diff::report(&changes, app.lang, app.locale, diff::styled)
+    };
+    Clear.render(t, &area);
+    let block = Block::default()
+        .borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow))
+        .title(app.lang.text("Changes - ↑↓ scroll, d close"))
+        .title_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(&area);
+    let text = text::wrap(&report, inner.width);
+    let lines = text.lines().count() as u16;
+    Paragraph::default()
+        .block(block)
+        .text(&text)
+        .scroll(comparison.scroll.min(lines.saturating_sub(inner.height)))
+        .render(t, &area);
+}
+
+/// A gauge, or its ASCII drawing, with a label that fits in it.
+fn draw_gauge(t: &mut Terminal<CvBackend>, app: &App, label: &str, percent: u16, color: Color, area: &Rect) {
+    let label = truncate(label, area.width as usize);
+    if app.options.ascii {
+        Paragraph::default()
+            .raw(true)
+            .text(&ascii_gauge(&label, percent, area.width))
+            .style(Style::default().fg(color))
+            .render(t, area);
+    } else {
+        Gauge::default()
+            .style(Style::default().fg(color).bg(Color::Black).modifier(Modifier::Italic))
+            .label(&text::cells(&label))
+            .percent(percent.min(100))
+            .render(t, area);
+    }
+}
+
+/// The skills of both sides, lined up, with a gauge for each side and how the levels differ.
+fn draw_compared_skills(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, comparison: &Comparison, area: &Rect) {
+    let pairs = comparison.skills();
+    let rows = area.height as usize;
+    // The name and the difference get a line of their own above the gauges when there is room
+    let tall = !screen.short && pairs.len() * 2 <= rows;
+    let shown = &pairs[..pairs.len().min(if tall { rows / 2 } else { rows })];
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&vec![Size::Fixed(if tall { 2 } else { 1 }); shown.len() + 1])
+        .render(t, area, |t, chunks| {
+            for (pair, chunk) in shown.iter().zip(chunks) {
+                let difference = match pair.difference() {
+                    Some(0) => String::from("="),
+                    Some(difference) if difference > 0 => format!("{{fg=green +{}}}", difference),
+                    Some(difference) => format!("{{fg=red {}}}", difference),
+                    None => String::new(),
+                };
+                let mut area = *chunk;
+                if tall {
+                    Paragraph::default()
+                        .text(&format!("{{mod=bold;fg=yellow {}}}  {}", markup::escape(&text::cells(pair.name)), difference))
+                        .render(t, &Rect::new(area.x, area.y, area.width, 1));
+                    area = Rect::new(area.x, area.y + 1, area.width, 1);
+                }
+                Group::default()
+                    .direction(Direction::Horizontal)
+                    .sizes(&[Size::Percent(50), Size::Percent(50)])
+                    .render(t, &area, |t, halves| {
+                        for (side, ((level, &(color, _)), half)) in pair.levels.iter().zip(&SIDES).zip(halves).enumerate() {
+                            let mut label = match *level {
+                                Some(level) => format!("{} / 100", level),
+                                None => String::from(app.lang.text("not listed")),
+                            };
+                            if !tall {
+                                label = format!("{}  {}", pair.name, label);
+                                match pair.difference() {
+                                    Some(difference) if side == 1 && difference != 0 => label.push_str(&format!("  {:+}", difference)),
+                                    _ => {}
+                                }
+                            }
+                            let percent = (f64::from(level.unwrap_or(0)) * app.progress()).round() as u16;
+                            draw_gauge(t, app, &label, percent, color, half);
+                        }
+                    });
+            }
+        });
+}
+
+/// The jobs of both sides, the same job side by side, their dates in red where they differ.
+fn draw_compared_experience(t: &mut Terminal<CvBackend>, app: &App, comparison: &Comparison, area: &Rect) {
+    let width = area.width / 2;
+    let cell = |job: Option<&Job>, other: Option<&Job>| match job {
+        Some(job) => {
+            let color = if other.is_some_and(|other| other.period != job.period) { "red" } else { "yellow" };
+            format!("{{mod=bold;fg={} {} ({})}}\n{}, {} ({})", color, app.locale.period(job.period),
+                    app.locale.duration(job.period.length(app.today)), markup::escape(&job.role),
+                    markup::escape(&job.company), markup::escape(&job.location))
+        }
+        None => format!("{{fg=gray {}}}", app.lang.text("No such job")),
+    };
+    let mut rows = vec![[0, 1].iter()
+        .map(|&side| {
+            let totals = comparison.people[side].experience_totals(app.today, app.lang, app.locale);
+            format!("{{mod=bold;fg=yellow {}:}} {}", totals[0].label, totals[0].value)
+        })
+        .collect::<Vec<String>>()];
+    for pair in comparison.jobs() {
+        rows.push(vec![cell(pair.jobs[0], pair.jobs[1]), cell(pair.jobs[1], pair.jobs[0])]);
+    }
+    // Each row is as high as its longest side, and there are as many as fit
+    let rows = rows.into_iter()
+        .map(|row| {
+            let row = row.iter().map(|text| text::wrap(text, width.saturating_sub(1))).collect::<Vec<String>>();
+            let height = row.iter().map(|text| text.lines().count()).max().unwrap_or(1) as u16 + 1;
+            (row, height)
+        })
+        .scan(0, |used, (row, height)| {
+            *used += height;
+            if *used > area.height { None } else { Some((row, height)) }
+        })
+        .collect::<Vec<(Vec<String>, u16)>>();
+    let mut sizes = rows.iter().map(|&(_, height)| Size::Fixed(height)).collect::<Vec<Size>>();
+    sizes.push(Size::Min(0));
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&sizes)
+        .render(t, area, |t, chunks| {
+            for ((row, _), chunk) in rows.iter().zip(chunks) {
+                Group::default()
+                    .direction(Direction::Horizontal)
+                    .sizes(&[Size::Percent(50), Size::Percent(50)])
+                    .render(t, chunk, |t, halves| {
+                        for (text, half) in row.iter().zip(halves) {
+                            Paragraph::default()
+                                .text(text)
+                                .style(Style::default().fg(Color::LightMagenta))
+                                .render(t, half);
+                        }
+                    });
+            }
+        });
+}
+
+fn draw_tabs(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let title = app.notice.as_ref().map_or(app.lang.text("Sections"), String::as_str);
+    let block = Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title(title).title_style(Style::default().fg(Color::Cyan));
+    if area.width >= app.tabs.width() + 2 {
+        let mut tabs = Tabs::default();
+        if !screen.short {
+            tabs.block(block);
+        }
+        tabs.titles(&app.tabs.titles)
+            .style(Style::default().fg(Color::Magenta))
+            .highlight_style(Style::default().fg(Color::Yellow))
+            .select(app.tabs.selection)
+            .render(t, area);
+    } else {
+        // Not enough room for every title: only show where we are and how to move on.
+        let text = format!(" {{mod=bold;fg=yellow ← {} →}}  {}/{}",
+                           app.tabs.titles[app.tabs.selection],
+                           app.tabs.selection + 1,
+                           app.tabs.titles.len());
+        let mut area = *area;
+        if !screen.short {
+            let mut block = block;
+            block.render(t, &area);
+            area = block.inner(&area);
+        }
+        Paragraph::default()
+            .text(&text)
+            .style(Style::default().fg(Color::Magenta))
+            .render(t, &area);
+    }
+}
+
+/// The steps of a new resume, the current one highlighted, over its form.
+fn draw_wizard(t: &mut Terminal<CvBackend>, app: &App, screen: Screen) {
+    let wizard = match app.wizard {
+        Some(ref wizard) => wizard,
+        None => return,
+    };
+    let header = if screen.short { 1 } else { 3 };
+    let steps = MyTabs {
+        titles: STEPS.iter().map(|step| app.lang.text(step)).collect(),
+        selection: wizard.step,
+    };
+    let title = app.lang.format("New resume {}", &[&wizard.path]);
+    let step = app.lang.format("Step {} of {}: {}", &[&(wizard.step + 1), &STEPS.len(), &steps.titles[wizard.step]]);
+    let hint = app.lang.text(match wizard.step {
+        0 => "Tab next, Enter new line, Ctrl+S next step, Esc quit",
+        _ if wizard.last() => "Tab next, Enter new line, Ctrl+N add an item, Ctrl+S write the file, Esc back",
+        _ => "Tab next, Enter new line, Ctrl+N add an item, Ctrl+S next step, Esc back",
+    });
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Fixed(header), Size::Min(0)])
+        .render(t, &app.size, |t, chunks| {
+            let mut block = Block::default()
+                .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                .title(&title)
+                .title_style(Style::default().fg(Color::Cyan));
+            if chunks[0].width >= steps.width() + 2 {
+                let mut tabs = Tabs::default();
+                if !screen.short {
+                    tabs.block(block);
+                }
+                tabs.titles(&steps.titles)
+                    .style(Style::default().fg(Color::Magenta))
+                    .highlight_style(Style::default().fg(Color::Yellow))
+                    .select(steps.selection)
+                    .render(t, &chunks[0]);
+            } else {
+                let mut area = chunks[0];
+                if !screen.short {
+                    block.render(t, &area);
+                    area = block.inner(&area);
+                }
                 Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("About me")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\nI am a Software Developer, Technical Writer, Developer Advocate,\n\
-                       and Open-Source Enthusiast with experience building\n\
-                       web applications. \n\n\
-                       I am keen on community work and sharing knowledge and have\n\
-                       a particular interest in backend and infrastructure projects.\n\n\
-                       My non-technical interests include knitting, baking,\n\
-                       & learning new natural languages.\n\n\
-                      ")
+                    .text(&format!(" {{mod=bold;fg=yellow {}}}", markup::escape(&step)))
+                    .render(t, &area);
+            }
+            draw_editor(t, app, &wizard.editor, &step, hint, &chunks[1]);
+        });
+}
+
+/// The form of the section being edited, or of a step of a new resume, scrolled so that the
+/// cursor is in view.
+fn draw_editor(t: &mut Terminal<CvBackend>, app: &App, editor: &Editor, title: &str, hint: &str, area: &Rect) {
+    let mut text = String::new();
+    for (i, input) in editor.inputs.iter().enumerate() {
+        if let Some((heading, number)) = input.heading {
+            text.push_str(&format!("\n{{mod=bold;fg=magenta {}}}\n", app.lang.format(heading, &[&number])));
+        }
+        let mut value = input.value.chars().map(|c| markup::escape(&c.to_string())).collect::<Vec<String>>();
+        if i == editor.selected {
+            // The character under the cursor inverted, a space at the end of a line
+            match value.get(editor.cursor).map(String::as_str) {
+                None | Some("\n") => value.insert(editor.cursor, String::from("{mod=invert  }")),
+                Some(c) => value[editor.cursor] = format!("{{mod=invert {}}}", c),
+            }
+        }
+        let value = value.concat();
+        let label = app.lang.text(input.label);
+        let color = if i == editor.selected { "yellow" } else { "cyan" };
+        if input.multiline {
+            text.push_str(&format!("{{mod=bold;fg={} {}:}}\n{}\n", color, label, value));
+        } else {
+            text.push_str(&format!("{{mod=bold;fg={} {}:}} {}\n", color, label, value));
+        }
+        if let Some(ref error) = input.error {
+            text.push_str(&format!("{{fg=red {}}}\n", markup::escape(error)));
+        }
+    }
+    let hint = if editor.discarding {
+        app.lang.text("Discard the changes? Esc again to discard, any other key to go on editing")
+    } else {
+        hint
+    };
+    let title = format!("{} - {}", title, hint);
+    let mut block = Block::default()
+        .borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow))
+        .title(&title)
+        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold));
+    block.render(t, area);
+    let inner = block.inner(area);
+    let (status, height) = match editor.status {
+        Some(ref status) => (format!("{{fg=red {}}}", markup::escape(status)), 2),
+        None => (String::from(app.lang.text("Items whose fields are all emptied are removed when saving.")), 1),
+    };
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Min(0), Size::Fixed(height)])
+        .render(t, &inner, |t, chunks| {
+            let text = text::wrap(text.trim_start_matches('\n'), chunks[0].width);
+            let cursor = text::styled_line(&text, "mod=invert").unwrap_or(0) as u16;
+            Paragraph::default()
+                .text(&text)
+                .scroll((cursor + 2).saturating_sub(chunks[0].height))
                 .style(Style::default().fg(Color::LightMagenta))
+                .render(t, &chunks[0]);
+            Paragraph::default()
+                .wrap(true)
+                .text(&status)
+                .style(Style::default().fg(Color::Gray))
                 .render(t, &chunks[1]);
+        });
+}
+
+fn draw_too_small(t: &mut Terminal<CvBackend>, lang: Lang, area: &Rect) {
+    let text = lang.format("\n{mod=bold;fg=yellow Terminal too small}\n\n\
+                            Please resize it to\n\
+                            at least {}x{}\n\
+                            (currently {}x{}).\n\n\
+                            Type {mod=bold;fg=yellow q} to exit.",
+                           &[&MIN_WIDTH, &MIN_HEIGHT, &area.width, &area.height]);
+    Paragraph::default()
+        .wrap(true)
+        .alignment(Alignment::Center)
+        .text(&text)
+        .style(Style::default().fg(Color::LightMagenta))
+        .render(t, area);
+}
+
+/// Builds the "{Label:} value" lines used by the About panes, without the blank lines around
+/// the entries when space is tight. The label of the focused entry, if any, is highlighted.
+fn fields(app: &App, entries: &[Field], screen: Screen, focus: Option<usize>) -> String {
+    let tight = screen.narrow || screen.short;
+    let mut text = String::from(if tight { "" } else { "\n" });
+    for (i, field) in entries.iter().enumerate() {
+        let modifier = if focus == Some(i) { "invert" } else { "bold" };
+        text.push_str(&format!("{{mod={};fg=yellow {}:}} {}{}", modifier, markup::escape(&field.label), app.text(&field.value), if tight { "\n" } else { "\n\n" }));
+    }
+    text
+}
+
+/// Paragraph text for a description, with some air between its paragraphs.
+fn description(text: &str) -> String {
+    format!("\n{}", text.replace('\n', "\n\n"))
+}
+
+/// Sizes of a centered area, dropping the margins when there is no room to spare.
+fn centered(tight: bool) -> [Size; 3] {
+    if tight {
+        [Size::Percent(0), Size::Percent(100), Size::Percent(0)]
+    } else {
+        [Size::Percent(10), Size::Percent(80), Size::Percent(10)]
+    }
+}
+
+fn draw_home(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let title = text::cells(&app.lang.format("{}'S RESUME", &[&app.resume.name.to_uppercase()]));
+    let help = format!("\n{}\n\n{}\n\n{}",
+                       app.lang.text("Use {mod=bold;fg=yellow ←}  and {mod=bold;fg=yellow →}  to navigate between the sections."),
+                       app.lang.text(if app.options.file.is_some() && app.options.variant.is_none() {
+                           "Type {mod=bold;fg=yellow l} to switch the language, {mod=bold;fg=yellow e} to edit a section."
+                       } else {
+                           "Type {mod=bold;fg=yellow l} to switch the language."
+                       }),
+                       app.lang.text(if app.team.is_some() {
+                           "Type {mod=bold;fg=yellow Esc} to go back to the people, {mod=bold;fg=yellow q} to exit."
+                       } else if app.history.is_some() {
+                           "Type {mod=bold;fg=yellow Esc} to go back to the versions, {mod=bold;fg=yellow q} to exit."
+                       } else {
+                           "Type {mod=bold;fg=yellow q} to exit the application."
+                       }));
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&centered(screen.short))
+        .render(t, area, |t, chunks| {
+                Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&centered(screen.narrow))
+                .render(t, &chunks[1], |t, chunks| {
+                    Block::default()
+                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                        .title(&title)
+                        .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
+                        .render(t, &chunks[1]);
+                    Group::default()
+                        .direction(Direction::Vertical)
+                        .margin(1)
+                        .sizes(&[Size::Fixed(7), Size::Min(0)])
+                        .render(t, &chunks[1], |t, chunks| {
+                            Paragraph::default()
+                            .wrap(true)
+                            .text(&help)
+                            .style(Style::default().fg(Color::LightMagenta))
+                            .render(t, &chunks[0]);
+                            // The banner is only drawn when it fits, wrapping it would garble it.
+                            let art = chunks[1];
+                            if art.width >= BANNER_WIDTH && art.height >= BANNER.len() as u16 {
+                                let padding = " ".repeat(((art.width - BANNER_WIDTH) / 2) as usize);
+                                let banner = BANNER.iter()
+                                    .map(|line| format!("{}{}", padding, line))
+                                    .collect::<Vec<String>>()
+                                    .join("\n");
+                                Paragraph::default()
+                                .raw(true)
+                                .text(&banner)
+                                .style(Style::default().fg(Color::LightMagenta))
+                                .render(t, &art);
+                                if app.options.animation {
+                                    // A brighter line goes down the banner, over and over
+                                    let line = (app.clock / BANNER_FRAMES) as usize % BANNER.len();
+                                    Paragraph::default()
+                                    .raw(true)
+                                    .text(&format!("{}{}", padding, BANNER[line]))
+                                    .style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
+                                    .render(t, &Rect::new(art.x, art.y + line as u16, art.width, 1));
+                                }
+                            }
+                        });
+                });
+        });
+}
+
+fn draw_about(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let resume = &app.resume;
+    let information = fields(app, &resume.information, screen, None);
+    let languages = fields(app, &resume.languages, screen, None);
+    let contact = fields(app, &resume.contact, screen, Some(app.contact));
+    let contact_title = if app.copied {
+        text::cells(&app.lang.format("Contact - {} copied to the clipboard", &[&resume.contact[app.contact].label]))
+    } else {
+        String::from(app.lang.text("Contact - ↑↓ select, y copy"))
+    };
+    let totals = fields(app, &resume.experience_totals(app.today, app.lang, app.locale), screen, None);
+    let about = format!("{}\n\n{}", app.text(&description(&resume.about)), totals.trim_start());
+    // The resume text of each pane, where its links are found
+    let values = |entries: &[Field]| entries.iter().map(|field| field.value.as_str()).collect::<Vec<&str>>().join("\n");
+    let panes = [(app.lang.text("Information"), information.as_str(), values(&resume.information)),
+                 (app.lang.text("Languages"), languages.as_str(), values(&resume.languages)),
+                 (contact_title.as_str(), contact.as_str(), values(&resume.contact)),
+                 (app.lang.text("About me"), about.as_str(), resume.about.clone())];
+    let draw_pane = |t: &mut Terminal<CvBackend>, &(title, text, ref links): &(&str, &str, String), area: &Rect| {
+        Paragraph::default()
+        .block(Block::default()
+            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+            .title(title)
+            .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
+        .wrap(true)
+        .text(&text::wrap(text, area.width.saturating_sub(2)))
+        .style(Style::default().fg(Color::LightMagenta))
+        .render(t, area);
+        app.draw_links(t, links, area);
+    };
+    if screen.narrow {
+        // Each field pane gets exactly the lines it needs, "About me" takes whatever is left.
+        let height = |text: &str| Size::Fixed(text.matches('\n').count() as u16 + 2);
+        Group::default()
+            .direction(Direction::Vertical)
+            .sizes(&[height(&information), height(&languages), height(&contact), Size::Min(0)])
+            .render(t, area, |t, chunks| {
+                for (pane, chunk) in panes.iter().zip(chunks) {
+                    draw_pane(t, pane, chunk);
+                }
             });
+        return;
+    }
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Percent(40), Size::Percent(60)])
+        .render(t, area, |t, chunks| {
+            for (row, area) in panes.chunks(2).zip(chunks) {
+                Group::default()
+                .direction(Direction::Horizontal)
+                .sizes(&[Size::Percent(50), Size::Percent(50)])
+                .render(t, area, |t, chunks| {
+                    for (pane, chunk) in row.iter().zip(chunks) {
+                        draw_pane(t, pane, chunk);
+                    }
+                });
+            }
+        });
+}
+
+/// Text stand-in for a `Gauge`, which only shows how full it is through colors.
+fn ascii_gauge(label: &str, percent: u16, width: u16) -> String {
+    let bar = (width as usize).saturating_sub(label.width() + 3);
+    let filled = bar * percent.min(100) as usize / 100;
+    format!("[{}{}] {}", "#".repeat(filled), "-".repeat(bar - filled), label)
+}
+
+/// Color of the charts of a skill, which stand out when the job description asks for it.
+fn skill_color(app: &App, skill: &Skill, color: Color) -> Color {
+    match app.report {
+        Some(ref report) if report.has_skill(&skill.name) => Color::Green,
+        _ => color,
+    }
+}
+
+/// The text cut to the given number of columns.
+fn truncate(text: &str, width: usize) -> String {
+    let mut truncated = String::new();
+    for c in text.chars() {
+        if truncated.width() + c.to_string().width() > width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated
+}
+
+/// Gauges for the skills, in as many columns as it takes to give each of them a line.
+fn draw_gauges(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, skills: &[(&Skill, Usage)], area: &Rect) {
+    if skills.is_empty() {
+        return;
+    }
+    let rows = area.height.max(1) as usize;
+    // The name gets a line of its own above the gauge when there is room for it.
+    let tall = !screen.short && skills.len() * 2 <= rows;
+    let per_column = if tall { skills.len() } else { rows };
+    let columns = skills.len().div_ceil(per_column);
+    Group::default()
+        .direction(Direction::Horizontal)
+        .sizes(&vec![Size::Percent(100 / columns as u16); columns])
+        .render(t, area, |t, chunks| {
+            for (column, chunk) in skills.chunks(per_column).zip(chunks) {
+                Group::default()
+                    .direction(Direction::Vertical)
+                    // The extra size soaks up the remaining space instead of the last gauge.
+                    .sizes(&vec![Size::Fixed(if tall { 2 } else { 1 }); column.len() + 1])
+                    .render(t, chunk, |t, chunks| {
+                        for (&(skill, ref usage), chunk) in column.iter().zip(chunks) {
+                            let level = (f64::from(skill.level) * app.progress()).round() as u16;
+                            let color = skill_color(app, skill, Color::Magenta);
+                            let mut area = *chunk;
+                            let label = if tall {
+                                let name = text::cells(&format!("{} - {}", skill.label(app.lang), usage.summary(app.lang, app.locale)));
+                                let mut title = Block::default().title(&name).title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold));
+                                title.render(t, &area);
+                                area = title.inner(&area);
+                                format!("{} / 100", skill.level)
+                            } else {
+                                format!("{}  {} / 100  {}", skill.label(app.lang), skill.level, usage.summary(app.lang, app.locale))
+                            };
+                            // `Gauge` cannot draw a label wider than itself, the usage goes first
+                            let label = if label.width() > area.width as usize {
+                                truncate(&format!("{}  {} / 100", skill.label(app.lang), skill.level), area.width as usize)
+                            } else {
+                                label
+                            };
+                            if app.options.ascii {
+                                Paragraph::default()
+                                    .raw(true)
+                                    .text(&ascii_gauge(&label, level, area.width))
+                                    .style(Style::default().fg(color))
+                                    .render(t, &area);
+                            } else {
+                                Gauge::default()
+                                    .style(Style::default()
+                                            .fg(color)
+                                            .bg(Color::Black)
+                                            .modifier(Modifier::Italic))
+                                    .label(&text::cells(&label))
+                                    .percent(level.min(100))
+                                    .render(t, &area);
+                            }
+                        }
+                    });
+            }
         });
 }
 
-fn draw_skills(t: &mut Terminal<RawBackend>, area: &Rect) {
+fn draw_bar_chart(t: &mut Terminal<CvBackend>, app: &App, skills: &[(&Skill, Usage)], area: &Rect) {
+    let names = skills.iter().map(|&(skill, _)| text::cells(&skill.name)).collect::<Vec<String>>();
+    let data = skills.iter().zip(&names)
+        .map(|(&(skill, _), name)| (name.as_str(), (f64::from(skill.level) * app.progress()).round() as u64))
+        .collect::<Vec<(&str, u64)>>();
+    // Bars share the width, with a gap between them, up to a width that still looks like a bar.
+    let bar_width = (area.width / skills.len().max(1) as u16).saturating_sub(1).clamp(1, 16);
+    BarChart::default()
+        .data(&data)
+        .max(100)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .style(Style::default().fg(Color::Magenta))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Magenta).modifier(Modifier::Bold))
+        .label_style(Style::default().fg(Color::Yellow))
+        .render(t, area);
+}
+
+/// Spider chart of the levels: one spoke per skill, around rings at every quarter.
+fn draw_radar(t: &mut Terminal<CvBackend>, app: &App, skills: &[(&Skill, Usage)], area: &Rect) {
+    if skills.is_empty() || area.width < 2 || area.height < 2 {
+        return;
+    }
+    let count = skills.len();
+    // Braille dots are about square, so the bounds follow the shape of the area to keep it round
+    let y_bound = 130.0;
+    let x_bound = y_bound * f64::from(area.width) / f64::from(area.height) / 2.0;
+    let point = |i: usize, level: f64| {
+        let angle = f64::consts::FRAC_PI_2 - 2.0 * f64::consts::PI * i as f64 / count as f64;
+        (level * angle.cos(), level * angle.sin())
+    };
+    let polygon = |ctx: &mut Context, levels: &dyn Fn(usize) -> f64, color: Color| {
+        for i in 0..count {
+            let (x1, y1) = point(i, levels(i));
+            let (x2, y2) = point((i + 1) % count, levels((i + 1) % count));
+            ctx.draw(&Line { x1, y1, x2, y2, color });
+        }
+    };
+    Canvas::default()
+        .x_bounds([-x_bound, x_bound])
+        .y_bounds([-y_bound, y_bound])
+        .paint(|ctx| {
+            for ring in &[25.0, 50.0, 75.0, 100.0] {
+                polygon(ctx, &|_| *ring, Color::DarkGray);
+            }
+            for i in 0..count {
+                let (x2, y2) = point(i, 100.0);
+                ctx.draw(&Line { x1: 0.0, y1: 0.0, x2, y2, color: Color::DarkGray });
+            }
+            ctx.layer();
+            polygon(ctx, &|i| f64::from(skills[i].0.level) * app.progress(), Color::Magenta);
+        })
+        .render(t, area);
+    // Canvas labels have to be static strings, so the names are drawn over it instead.
+    for (i, &(skill, _)) in skills.iter().enumerate() {
+        let label = text::visual(&format!("{} {}", skill.name, skill.level));
+        let width = (label.width() as u16).min(area.width);
+        let (x, y) = point(i, 112.0);
+        let column = area.left() + ((x + x_bound) / (2.0 * x_bound) * f64::from(area.width - 1)) as u16;
+        let row = area.top() + ((y_bound - y) / (2.0 * y_bound) * f64::from(area.height - 1)) as u16;
+        // Labels go outwards from the end of their spoke
+        let left = if x > 1.0 {
+            column
+        } else if x < -1.0 {
+            column.saturating_sub(width)
+        } else {
+            column.saturating_sub(width / 2)
+        };
+        let left = left.max(area.left()).min(area.right() - width);
+        Paragraph::default()
+            .raw(true)
+            .text(&label)
+            .style(Style::default().fg(skill_color(app, skill, Color::Yellow)).modifier(Modifier::Bold))
+            .render(t, &Rect::new(left, row, width, 1));
+    }
+}
+
+/// Columns of the skill matrix, with the level each of them starts at.
+const PROFICIENCIES: [(&str, u16); 4] = [("Learning", 0), ("Intermediate", 50), ("Advanced", 70), ("Expert", 85)];
+
+/// Lines of comma separated names, filled up to the given width.
+fn wrap_names(names: &[&str], width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for name in names {
+        match lines.last_mut() {
+            Some(line) if line.width() + name.width() + 2 <= width => {
+                line.push_str(", ");
+                line.push_str(name);
+            }
+            Some(line) => {
+                line.push(',');
+                lines.push(String::from(*name));
+            }
+            None => lines.push(String::from(*name)),
+        }
+    }
+    lines
+}
+
+/// Table of the skills with a row per category and a column per proficiency.
+fn draw_matrix(t: &mut Terminal<CvBackend>, lang: Lang, skills: &[(&Skill, Usage)], area: &Rect) {
+    let mut categories: Vec<&str> = Vec::new();
+    for &(skill, _) in skills {
+        if !categories.contains(&skill.category.as_str()) {
+            categories.push(&skill.category);
+        }
+    }
+    let first = categories.iter().map(|category| category.width()).max().unwrap_or(0) as u16;
+    let columns = PROFICIENCIES.len() as u16;
+    let width = (area.width.saturating_sub(first + 1) / columns).saturating_sub(1);
+    let mut widths = vec![first];
+    widths.extend(vec![width; columns as usize]);
+    // A category takes as many lines as its fullest cell needs
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for category in categories {
+        let cells = PROFICIENCIES.iter().enumerate()
+            .map(|(i, &(_, start))| {
+                let end = PROFICIENCIES.get(i + 1).map_or(u16::MAX, |&(_, level)| level);
+                let names = skills.iter()
+                    .filter(|&&(skill, _)| skill.category == category && skill.level >= start && skill.level < end)
+                    .map(|&(skill, _)| skill.name.as_str())
+                    .collect::<Vec<&str>>();
+                wrap_names(&names, width as usize)
+            })
+            .collect::<Vec<Vec<String>>>();
+        let height = cells.iter().map(|lines| lines.len()).max().unwrap_or(0).max(1);
+        for line in 0..height {
+            let mut row = vec![if line == 0 { text::cells(category) } else { String::new() }];
+            row.extend(cells.iter().map(|lines| lines.get(line).map(|line| text::cells(line)).unwrap_or_default()));
+            rows.push(row);
+        }
+    }
+    let header = iter::once("").chain(PROFICIENCIES.iter().map(|&(name, _)| lang.text(name)));
+    Table::new(header, rows.iter().map(|row| Row::Data(row.iter())))
+        .header_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold))
+        .widths(&widths)
+        .style(Style::default().fg(Color::LightMagenta))
+        .render(t, area);
+}
+
+fn draw_skills(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let skills = app.skills();
+    let title = app.lang.format("Programming Languages ({}, {}) - v view, s sort",
+                                &[&app.lang.text(app.skill_view.name()), &app.lang.text(app.skill_order.name())]);
+    let others = &app.resume.others;
     Group::default()
         .direction(Direction::Vertical)
         .sizes(&[Size::Percent(60), Size::Percent(40)])
         .render(t, area, |t, chunks| {
-            Block::default()
+            let mut block = Block::default()
                 .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                .title("Programming Languages")
-                .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                .render(t, &chunks[0]);
-            Group::default()
-                .direction(Direction::Vertical)
-                .margin(1)
-                .sizes(&[Size::Fixed(2),Size::Fixed(2),Size::Fixed(2),Size::Fixed(2),Size::Fixed(2),Size::Fixed(2),Size::Fixed(2)])
-                .render(t, &chunks[0], |t, chunks| {
-                Gauge::default()
-                    .block(Block::default().title("Go").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("70 / 100"))
-                    .percent(70)
-                    .render(t, &chunks[0]);
-                Gauge::default()
-                    .block(Block::default().title("JavaScript").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("70 / 100"))
-                    .percent(70)
-                    .render(t, &chunks[1]);
-                Gauge::default()
-                    .block(Block::default().title("PHP").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("70 / 100"))
-                    .percent(70)
-                    .render(t, &chunks[2]);
-                Gauge::default()
-                    .block(Block::default().title("Ruby").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("60 / 100"))
-                    .percent(60)
-                    .render(t, &chunks[3]);
-                Gauge::default()
-                    .block(Block::default().title("Rust (learning)").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("40 / 100"))
-                    .percent(40)
-                    .render(t, &chunks[4]);
-                Gauge::default()
-                    .block(Block::default().title("Python (learning)").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .style(Style::default()
-                            .fg(Color::Magenta)
-                            .bg(Color::Black)
-                            .modifier(Modifier::Italic))
-                    .label(&format!("40 / 100"))
-                    .percent(40)
-                    .render(t, &chunks[5]);
-            });
+                .title(&title)
+                .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold));
+            block.render(t, &chunks[0]);
+            let inner = block.inner(&chunks[0]);
+            match app.skill_view {
+                SkillView::Gauges => draw_gauges(t, app, screen, &skills, &inner),
+                SkillView::Bars => draw_bar_chart(t, app, &skills, &inner),
+                SkillView::Radar => draw_radar(t, app, &skills, &inner),
+                SkillView::Matrix => draw_matrix(t, app.lang, &skills, &inner),
+            }
             Block::default()
                 .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                .title("Others")
+                .title(app.lang.text("Others"))
                 .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
                 .render(t, &chunks[1]);
+            if screen.narrow {
+                // The lists do not fit side by side, so collapse each of them into a line.
+                let text = others.iter()
+                    .map(|group| format!("{{mod=bold;fg=yellow {}:}} {}", group.title, group.items.join(", ")))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                let area = chunks[1].inner(1);
+                Paragraph::default()
+                    .wrap(true)
+                    .text(&text::wrap(&text, area.width))
+                    .style(Style::default().fg(Color::LightMagenta))
+                    .render(t, &area);
+                return;
+            }
             Group::default()
                 .direction(Direction::Horizontal)
                 .margin(1)
-                .sizes(&[Size::Fixed(15),Size::Fixed(2),Size::Fixed(24),Size::Fixed(2),Size::Fixed(25),Size::Fixed(2),Size::Fixed(15),Size::Fixed(2),Size::Fixed(16),Size::Fixed(2)])
+                .sizes(&vec![Size::Percent(100 / others.len().max(1) as u16); others.len()])
                 .render(t, &chunks[1], |t, chunks| {
-                    SelectableList::default()
-                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Frameworks").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .items(&vec!["Symfony", "Laravel", "Rails"])
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[0]);
-                    SelectableList::default()
-                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Technologies").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .items(&vec!["GNU / Linux", "OSX", "MySQL", "AJAX", "jQuery", "OOP", "MVC", "Wordpress", "Prometheus", "Docker"])
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[2]);
-                    SelectableList::default()
-                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Areas").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .items(&vec!["Web Development", "Databases", "Software Engineering", "Monitoring", "Project Management"])
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[4]);
-                    SelectableList::default()
-                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Version Control").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .items(&vec!["Git", "Mercurial", "SVN"])
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[6]);
-                    SelectableList::default()
-                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title("Task Tracking").title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .items(&vec!["JIRA", "AutoTask", "Trello", "Asana"])
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[8]);
+                    for (group, chunk) in others.iter().zip(chunks) {
+                        let items = group.items.iter().map(|item| text::cells(item)).collect::<Vec<String>>();
+                        SelectableList::default()
+                            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)).title(&text::cells(&group.title)).title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
+                            .items(&items)
+                            .style(Style::default().fg(Color::LightMagenta))
+                            .render(t, chunk);
+                    }
             });
     });
 }
 
-fn draw_experience(t: &mut Terminal<RawBackend>, area: &Rect) {
+/// Equal heights for a stack of entries.
+fn stacked(count: usize) -> Vec<Size> {
+    vec![Size::Percent(100 / count.max(1) as u16); count]
+}
+
+/// Draws entries on top of each other, inside a titled block, with how long they lasted when
+/// `today` is given.
+fn draw_entries(t: &mut Terminal<CvBackend>, app: &App, title: &str, entries: &[Entry], today: Option<Date>, area: &Rect) {
+    Block::default()
+        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+        .title(title)
+        .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
+        .render(t, area);
     Group::default()
         .direction(Direction::Vertical)
-        .sizes(&[Size::Percent(15), Size::Percent(15), Size::Percent(25),Size::Percent(25), Size::Percent(19),Size::Fixed(1)])
-
+        .margin(1)
+        .sizes(&stacked(entries.len()))
         .render(t, area, |t, chunks| {
+            for (entry, chunk) in entries.iter().zip(chunks) {
                 Paragraph::default()
                 .block(Block::default()
                     .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("2018 - current: Freelancer, Self-Employed (remote)")
-                    .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\nPerform ongoing consultative and development work ranging from website maintenance/upgrades to business strategy and development.\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[0]);
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("2017 - current: Contributor, Fixate IO (remote)")
-                    .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\nGenerate in-depth technical content on an ongoing basis; Conduct market research and perform industry analysis;\n\n\
-                       Cover wide range of topics relating to software development, memory handling, etc.\n\n\
-                       Took over two projects to refactor, maintain and add new features.\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[1]);
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("2017 - 2017: Software Developer, Project A Services GmbH (Germany)")
+                    .title(&text::cells(&entry.heading(today, app.locale)))
                     .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
                 .wrap(true)
-                .text("\nSupported the backend development team by assisting them on various venture projects using Symfony and PHP; Collaborated \n\n\
-                        with Product Managers to foster and implement Agile practices; Analyzed an existing prototype application, refactored it\n\n\
-                        and added features, following the company's best practices and software development principles\n\n\
-                      ")
+                .text(&app.paragraph(&description(&entry.description), chunk))
                 .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[2]);
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("2014 - 2015: Web Developer, eKomi Ltd (Germany)")
-                    .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\nSupported IT team with both frontend and backend development and debugging tasks while working with large codebase;\n\n\
-                       Performed both client-facing work (designing customized, responsive review pages for clients) and internal tooling\n\n\
-                       for the rest of the team\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[3]);
-                Paragraph::default()
-                .block(Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("2011 - 2012: Software Developer, GrantStream Inc (Canada)")
-                    .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                .wrap(true)
-                .text("\nResponsible for a variety of development projects in grant management software including maintenance of PHP applications\n\n\
-                        and MS SQL Server and MySQL databases; Configured UI of customized Web applications with PHP, MSSQL, JavaScript, jQuery, CSS\n\n\
-                      ")
-                .style(Style::default().fg(Color::LightMagenta))
-                .render(t, &chunks[4]);
-    });
+                .render(t, chunk);
+                app.draw_links(t, &entry.description, chunk);
+            }
+        });
+}
+
+/// The jobs whose paragraphs are shown: short terminals have no room for every paragraph, only
+/// the selected one is shown, when there is one.
+fn shown_jobs(count: usize, selected: usize, short: bool) -> Range<usize> {
+    if short && selected < count {
+        selected..selected + 1
+    } else {
+        0..count
+    }
 }
 
-fn draw_education(t: &mut Terminal<RawBackend>, area: &Rect) {
+fn draw_experience(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let jobs = &app.resume.experience;
+    let shown = shown_jobs(jobs.len(), app.job, screen.short);
+    let bars = jobs.iter().map(|job| (job.company.as_str(), job.period)).collect::<Vec<(&str, Period)>>();
+    let periods = jobs.iter().map(|job| job.period).collect::<Vec<Period>>();
+    // One line per lane of the timeline, plus the scale and the borders
+    let height = timeline::lanes(&periods, app.today).into_iter().max().map_or(0, |lane| lane as u16 + 1) + 3;
     Group::default()
         .direction(Direction::Vertical)
-        .sizes(&[Size::Percent(45), Size::Percent(55)])
+        .sizes(&[Size::Fixed(height), Size::Min(0)])
         .render(t, area, |t, chunks| {
-                Block::default()
+            Timeline::default()
+                .block(Block::default()
                     .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Education")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                    .render(t, &chunks[0]);
-                Group::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .sizes(&[Size::Percent(33), Size::Percent(33), Size::Percent(33)])
-                    .render(t, &chunks[0], |t, chunks| {
-                        Paragraph::default()
-                        .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("University of Toronto: Certificate in Project Management (2010 - 2011)")
-                            .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .wrap(true)
-                        .text("\nStudied foundations of project management and how to apply the most effective tools & techniques to achieve project objectives\n\n\
-                            ")
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[0]);
-                        Paragraph::default()
-                        .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("University of Western Ontario: Bachelor of Arts (2004 - 2009)")
-                            .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .wrap(true)
-                        .text("\nObtained Double Major in Computer Science and Media Studies\n\n\
-                            ")
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[1]);
+                    .title(app.lang.text("Timeline - ↑↓ select"))
+                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
+                .bars(&bars)
+                .select(Some(app.job))
+                .today(app.today)
+                .progress(app.progress())
+                .style(Style::default().fg(Color::Magenta))
+                .highlight_style(Style::default().fg(Color::Yellow))
+                .render(t, &chunks[0]);
+            if jobs.is_empty() {
+                Paragraph::default()
+                    .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)))
+                    .text(&format!("\n{}", app.lang.text("No jobs")))
+                    .style(Style::default().fg(Color::LightMagenta))
+                    .render(t, &chunks[1]);
+                return;
+            }
+            Group::default()
+                .direction(Direction::Vertical)
+                .sizes(&stacked(shown.len()))
+                .render(t, &chunks[1], |t, chunks| {
+                    for (i, chunk) in shown.clone().zip(chunks) {
+                        let job = &jobs[i];
+                        // The paragraph of the job selected on the timeline is framed in the same color
+                        let border = if i == app.job { Color::Yellow } else { Color::Cyan };
                         Paragraph::default()
                         .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("Stendhal University: Exchange Program (2008)")
+                            .borders(Borders::ALL).border_style(Style::default().fg(border))
+                            .title(&text::cells(&job.title(app.today, app.locale)))
                             .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
                         .wrap(true)
-                        .text("\nParticipated in Academic Exchange Program in Grenoble through the University of Western Ontario\n\n\
-                            ")
+                        .text(&app.paragraph(&description(&job.description), chunk))
                         .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[2]);
-                });
-                Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Continuing Education")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                    .render(t, &chunks[1]);
-                Group::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .sizes(&[Size::Percent(23), Size::Percent(23),Size::Percent(23), Size::Percent(31)])
-                    .render(t, &chunks[1], |t, chunks| {
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Getting Started with Continuous Delivery (2018)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nO'Reilly Live Online Training Course")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[0]);
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Practical Kubernetes (2018)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nO'Reilly Live Online Training Course")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[1]);
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Bill Kennedy's Ultimate Go Workshop (2018)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nA weekend course designed to provide an intensive idiomatic view of Go")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[2]);
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Women Techmakers - JavaScript Crash Course (2017-2018)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nA 12-week lecture course designed to expose participants to multiple levels of the software development stack\n\
-                    with introductions to Node.js, Vue.js, MongoDB, Unit Testing, CI/CD, Design Patterns, Bridging APIs, and more")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[3]);
+                        .render(t, chunk);
+                        app.draw_links(t, &job.description, chunk);
+                    }
                 });
-    });
+        });
 }
 
-fn draw_projects(t: &mut Terminal<RawBackend>, area: &Rect) {
+fn draw_education(t: &mut Terminal<CvBackend>, app: &App, area: &Rect) {
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Percent(45), Size::Percent(55)])
+        .render(t, area, |t, chunks| {
+            draw_entries(t, app, app.lang.text("Education"), &app.resume.education, Some(app.today), &chunks[0]);
+            // Courses only have the year they were taken in, which says nothing of how long they lasted
+            draw_entries(t, app, app.lang.text("Continuing Education"), &app.resume.courses, None, &chunks[1]);
+        });
+}
+
+fn draw_projects(t: &mut Terminal<CvBackend>, app: &App, area: &Rect) {
     Group::default()
         .direction(Direction::Vertical)
         .sizes(&[Size::Percent(50), Size::Percent(25), Size::Percent(25)])
         .render(t, area, |t, chunks| {
-                Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Personal Projects")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                    .render(t, &chunks[0]);
-                Group::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .sizes(&[Size::Percent(40), Size::Percent(40), Size::Percent(20)])
-                    .render(t, &chunks[0], |t, chunks| {
-                        Paragraph::default()
-                        .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("Observability in the Kitchen")
-                            .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .wrap(true)
-                        .text("\nThis project leverages sensors, open-source software, and Go to improve breadmaking and explores the relationship between\n\
-                        sourdough cultures, humidity, and temperature and how one can use systems monitoring tools to gain insight into an age-old tradition.\n\n\
-                            ")
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[0]);
-                        Paragraph::default()
-                        .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("Wortschatz Logger")
-                            .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .wrap(true)
-                        .text("\nWeb application aimed at helping people familiarize themselves with German articles through personal user accounts that allows\n\
-                        word tracking/categorizing and interactive quizzes. Built with PHP Laravel Framework, PostgreSQL, JavaScript, jQuery, SASS.\n\n\
-                            ")
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[1]);
-                        Paragraph::default()
-                        .block(Block::default()
-                            .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                            .title("Der Die Das Game")
-                            .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                        .wrap(true)
-                        .text("\nHTML5 browser game built with the Phaser.io framework and fully programmed in JavaScript. \n\n\
-                            ")
-                        .style(Style::default().fg(Color::LightMagenta))
-                        .render(t, &chunks[2]);
-                });
-                Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Volunteer Work")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                    .render(t, &chunks[1]);
-                Group::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .sizes(&[Size::Percent(99)])
-                    .render(t, &chunks[1], |t, chunks| {
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Rails Girls Berlin (2017 - current)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nVolunteer as a coach for the Rails Girls Berlin Community, an organization aimed at mentoring and encouraging women\n\
-                    with no programming experience to learn the full programming stack and gain practical experience by building their\n\
-                    own Ruby on Rails app in a safe and welcoming space")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[0]);
-                });
-                Block::default()
-                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                    .title("Open-Source Contributions")
-                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold))
-                    .render(t, &chunks[2]);
-                Group::default()
-                    .direction(Direction::Vertical)
-                    .margin(1)
-                    .sizes(&[Size::Percent(99)])
-                    .render(t, &chunks[2], |t, chunks| {
-                    Paragraph::default()
-                    .block(Block::default()
-                        .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("Prometheus (2017 - current)")
-                        .title_style(Style::default().fg(Color::Yellow).modifier(Modifier::Bold)))
-                    .wrap(true)
-                    .text("\nOngoing contributions to a systems monitoring toolkit written in Go: add new default metric go_info to Go client library;\n\
-                    document config options; add new features to command line utility; create custom node exporter for BME280 module; ...
-                     ")
-                    .style(Style::default().fg(Color::LightMagenta))
-                    .render(t, &chunks[0]);
-                });
-    });
+            draw_entries(t, app, app.lang.text("Personal Projects"), &app.resume.projects, None, &chunks[0]);
+            draw_entries(t, app, app.lang.text("Volunteer Work"), &app.resume.volunteering, None, &chunks[1]);
+            draw_entries(t, app, app.lang.text("Open-Source Contributions"), &app.resume.contributions, None, &chunks[2]);
+        });
 }
 
-fn draw_objective(t: &mut Terminal<RawBackend>, area: &Rect) {
+fn draw_objective(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let objective = &app.resume.objective;
+    let mut text = format!("\n{{mod=bold;fg=yellow {}}}\n\n\n{}\n\n", markup::escape(&objective.headline), app.text(&objective.intro));
+    for goal in &objective.goals {
+        text.push_str(&format!("\t* {}\n", app.text(&goal.text)));
+    }
     Group::default()
         .direction(Direction::Vertical)
-        .sizes(&[Size::Percent(10), Size::Percent(80), Size::Percent(10)])
+        .sizes(&centered(screen.short))
         .render(t, area, |t, chunks| {
                 Group::default()
                 .direction(Direction::Horizontal)
-                .sizes(&[Size::Percent(10), Size::Percent(80), Size::Percent(10)])
+                .sizes(&centered(screen.narrow))
                 .render(t, &chunks[1], |t, chunks| {
                     Paragraph::default()
                     .block(Block::default()
                         .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
-                        .title("What I am looking for?")
+                        .title(app.lang.text("What I am looking for?"))
                         .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
                     .wrap(true)
-                    .text("\n{mod=bold;fg=yellow I am always open to be part of a team that does interesting work. :)}\n\n\n\
-                           My ideal role involves a combination of the following:\n\n\
-                           \t* Solve interesting backend and infrastructure problems\n\
-                           \t* Create and improve the tools used during the development\n\
-                           \t* Maintain a highly performant and reliable system\n\
-                           \t* Create and integrate APIs to expose and extend functionality\n\
-                           \t* Documentation\n\
-                           \t* Contribute to open source software\n\
-                           \t* Opportunities to attend and speak at conferences\n\
-                           \t* Maintain healthy work-life balance\n\
-                           \t* Receive and give mentorshop\
-                           ")
+                    .text(&text::wrap(&text, chunks[1].width.saturating_sub(2)))
                     .style(Style::default().fg(Color::LightMagenta))
                     .render(t, &chunks[1]);
                 });
         });
 }
+
+fn draw_match(t: &mut Terminal<CvBackend>, app: &App, screen: Screen, area: &Rect) {
+    let report = match app.report {
+        Some(ref report) => report,
+        None => return,
+    };
+    let gap = if screen.short { "\n" } else { "\n\n" };
+    let list = |items: &[&str]| if items.is_empty() { String::from(app.lang.text("none")) } else { markup::escape(&items.join(", ")) };
+    let skills = report.skills.iter().map(String::as_str).collect::<Vec<&str>>();
+    let technologies = report.technologies.iter().map(String::as_str).collect::<Vec<&str>>();
+    let mut text = String::new();
+    for &(label, ref items) in &[("Matching skills", list(&skills)),
+                                 ("Matching technologies", list(&technologies)),
+                                 ("Keywords found in the resume", list(&report.words(Found::Text))),
+                                 ("Missing keywords", list(&report.words(Found::Missing)))] {
+        text.push_str(&format!("{{mod=bold;fg=yellow {}:}} {}{}", app.lang.text(label), items, gap));
+    }
+    text.push_str(&format!("{{mod=bold;fg=yellow {}}}\n", app.lang.text("Most relevant roles:")));
+    for (rank, &(i, relevance)) in report.roles.iter().enumerate() {
+        let job = &app.resume.experience[i];
+        text.push_str(&app.lang.format("{}. {}, {} (relevance {})", &[&(rank + 1), &markup::escape(&job.role), &markup::escape(&job.company), &relevance]));
+        text.push('\n');
+    }
+    let score = app.lang.format("{} of the keywords of the job description", &[&app.locale.percent(report.score())]);
+    Group::default()
+        .direction(Direction::Vertical)
+        .sizes(&[Size::Fixed(3), Size::Min(0)])
+        .render(t, area, |t, chunks| {
+            let mut block = Block::default()
+                .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                .title(app.lang.text("Match"))
+                .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold));
+            block.render(t, &chunks[0]);
+            let inner = block.inner(&chunks[0]);
+            let percent = (f64::from(report.score()) * app.progress()).round() as u16;
+            if app.options.ascii {
+                Paragraph::default()
+                    .raw(true)
+                    .text(&ascii_gauge(&score, percent, inner.width))
+                    .style(Style::default().fg(Color::Green))
+                    .render(t, &inner);
+            } else {
+                Gauge::default()
+                    .style(Style::default().fg(Color::Green).bg(Color::Black).modifier(Modifier::Italic))
+                    .label(&truncate(&score, inner.width as usize))
+                    .percent(percent.min(100))
+                    .render(t, &inner);
+            }
+            Paragraph::default()
+                .block(Block::default()
+                    .borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))
+                    .title(app.lang.text("Job description"))
+                    .title_style(Style::default().fg(Color::Magenta).modifier(Modifier::Bold)))
+                .wrap(true)
+                .text(&text::wrap(&text, chunks[1].width.saturating_sub(2)))
+                .style(Style::default().fg(Color::LightMagenta))
+                .render(t, &chunks[1]);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(resume: Resume) -> App<'static> {
+        let lang = Lang::English;
+        App {
+            size: Rect::default(),
+            tabs: MyTabs {
+                titles: titles(lang, false),
+                selection: 0,
+            },
+            options: Options::default(),
+            resume: resume.clone(),
+            original: resume,
+            lang,
+            locale: Locale::of(lang),
+            contact: 0,
+            copied: false,
+            skill_view: SkillView::Gauges,
+            skill_order: SkillOrder::Listed,
+            job: 0,
+            today: Date::year(2020),
+            clock: 0,
+            shown_at: 0,
+            description: None,
+            report: None,
+            team: None,
+            comparison: None,
+            history: None,
+            editor: None,
+            notice: None,
+            wizard: None,
+        }
+    }
+
+    /// Empties every input of the editor, which removes the items they belong to.
+    fn empty_all(editor: &mut Editor) {
+        for input in &mut editor.inputs {
+            input.value.clear();
+        }
+    }
+
+    #[test]
+    fn saving_without_the_selected_contact_and_job_moves_back_the_selections() {
+        let path = env::temp_dir().join(format!("rust-cv-save-{}.toml", process::id()));
+        let mut app = app(Resume::builtin());
+        app.options.file = Some(path.display().to_string());
+        app.contact = app.resume.contact.len() - 1;
+        app.job = app.resume.experience.len() - 1;
+        app.copied = true;
+        for section in &[ABOUT, EXPERIENCE] {
+            app.tabs.selection = *section;
+            app.edit();
+            empty_all(app.editor.as_mut().unwrap());
+            app.save();
+            assert!(app.editor.is_none(), "{:?}", app.notice);
+        }
+        fs::remove_file(&path).unwrap();
+        assert!(app.resume.contact.is_empty() && app.resume.experience.is_empty());
+        assert_eq!((app.contact, app.job, app.copied), (0, 0, false));
+    }
+
+    #[test]
+    fn short_screens_show_the_selected_job() {
+        assert_eq!(shown_jobs(3, 1, true), 1..2);
+        assert_eq!(shown_jobs(3, 1, false), 0..3);
+    }
+
+    #[test]
+    fn no_jobs_show_nothing() {
+        assert!(shown_jobs(0, 0, true).is_empty());
+        assert!(shown_jobs(0, 0, false).is_empty());
+    }
+
+    #[test]
+    fn ascii_gauges_are_full_at_most() {
+        assert_eq!(ascii_gauge("Go", 50, 15), "[#####-----] Go");
+        assert_eq!(ascii_gauge("Go", 150, 15), "[##########] Go");
+    }
+
+    #[test]
+    fn descriptions_are_shown_as_written() {
+        let app = app(Resume::default());
+        let description = "Wrote {mod=bold} in a \\ {link=https://a.org {a}} and fn main() { }";
+        let area = Rect::new(0, 0, 80, 1);
+        let mut buffer = Buffer::empty(area);
+        Paragraph::default().text(&app.paragraph(description, &area)).draw(&area, &mut buffer);
+        // The symbols the backend draws in place of the escaped braces and backslashes
+        let shown = (0..area.width)
+            .map(|x| match buffer.get(x, 0).symbol.as_str() {
+                symbol if symbol == markup::CLOSING_BRACE => "}",
+                symbol if symbol == markup::BACKSLASH => "\\",
+                symbol => symbol,
+            })
+            .collect::<String>();
+        assert_eq!(shown.trim_end(), "Wrote {mod=bold} in a \\ {a <https://a.org>} and fn main() { }");
+        assert!(buffer.get(24, 0).style.modifier == Modifier::Underline);
+    }
+}
@@ -0,0 +1,182 @@
+use tui::buffer::Buffer;
+use tui::layout::Rect;
+use tui::style::Modifier;
+use tui::widgets::Widget;
+
+/// A `{link=URL label}` found in the resume text.
+pub struct Link<'a> {
+    pub url: &'a str,
+    pub label: &'a str,
+}
+
+/// Splits the text around its links: the text before each link, the link itself and whatever
+/// follows the last one.
+fn split(text: &str) -> (Vec<(&str, Link<'_>)>, &str) {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{link=") {
+        let link = &rest[start + 6..];
+        let end = match link.find('}') {
+            Some(end) => end,
+            None => break,
+        };
+        let (url, label) = match link[..end].find(' ') {
+            Some(space) => (&link[..space], &link[space + 1..end]),
+            None => (&link[..end], &link[..end]),
+        };
+        parts.push((&rest[..start], Link { url, label }));
+        rest = &link[end + 1..];
+    }
+    (parts, rest)
+}
+
+pub fn links(text: &str) -> Vec<Link<'_>> {
+    split(text).0.into_iter().map(|(_, link)| link).collect()
+}
+
+/// Whether the label already tells where the link goes, as with a bare URL or an email.
+fn self_describing(link: &Link) -> bool {
+    link.url.contains(link.label)
+}
+
+/// Turns resume text into `Paragraph` markup: links become underlined labels, followed by their
+/// URL when the terminal cannot open them itself, and the rest is escaped.
+pub fn styled(text: &str, hyperlinks: bool) -> String {
+    let (parts, rest) = split(text);
+    let mut styled = String::new();
+    for (before, link) in parts {
+        styled.push_str(&escape(before));
+        styled.push_str(&format!("{{mod=underline {}}}", escape(link.label)));
+        if !hyperlinks && !self_describing(&link) {
+            styled.push_str(&format!(" <{}>", escape(link.url)));
+        }
+    }
+    styled.push_str(&escape(rest));
+    styled
+}
+
+/// Stands for a closing brace in escaped text: `Paragraph` ends a style at any `}` in it, escaped
+/// or not, so the backend draws this one in its place.
+pub const CLOSING_BRACE: &str = "\u{e07d}";
+
+/// Stands for a backslash in escaped text: an escaped backslash keeps `Paragraph` escaping until
+/// the next `{`, which would then not start a style, so the backend draws this one in its place.
+pub const BACKSLASH: &str = "\u{e05c}";
+
+/// Text typed by the user, escaped so that `Paragraph` shows its braces and backslashes.
+pub fn escape(text: &str) -> String {
+    text.replace('\\', BACKSLASH).replace('{', "\\{").replace('}', CLOSING_BRACE)
+}
+
+/// Plain text version of the links, for output that has no styling at all.
+pub fn plain(text: &str) -> String {
+    let (parts, rest) = split(text);
+    let mut plain = String::new();
+    for (before, link) in parts {
+        plain.push_str(before);
+        plain.push_str(link.label);
+        if !self_describing(&link) {
+            plain.push_str(&format!(" ({})", link.url));
+        }
+    }
+    plain.push_str(rest);
+    plain
+}
+
+/// Markdown version of the links, as `[label](url)`.
+pub fn markdown(text: &str) -> String {
+    let (parts, rest) = split(text);
+    let mut markdown = String::new();
+    for (before, link) in parts {
+        markdown.push_str(before);
+        markdown.push_str(&format!("[{}]({})", link.label, link.url));
+    }
+    markdown.push_str(rest);
+    markdown
+}
+
+/// What to copy for a piece of text: the address of its link, or the text itself.
+pub fn copyable(text: &str) -> String {
+    match links(text).first() {
+        Some(link) => String::from(link.url.trim_start_matches("mailto:").trim_start_matches("tel:")),
+        None => plain(text),
+    }
+}
+
+/// Makes the links of a paragraph clickable with OSC 8 escape sequences.
+///
+/// It is rendered over the paragraph, once the labels have been laid out: the underlined runs of
+/// cells are the labels, in the same order as in the text. The escape sequences go in the symbols
+/// of the first and last cell of each run so they do not count in the width of the text.
+pub struct Hyperlinks<'a> {
+    links: &'a [Link<'a>],
+}
+
+impl<'a> Hyperlinks<'a> {
+    pub fn new(links: &'a [Link<'a>]) -> Hyperlinks<'a> {
+        Hyperlinks { links }
+    }
+}
+
+impl<'a> Widget for Hyperlinks<'a> {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let mut runs = Vec::new();
+        for y in area.top()..area.bottom() {
+            let mut x = area.left();
+            while x < area.right() {
+                if buf.get(x, y).style.modifier != Modifier::Underline {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < area.right() && buf.get(x, y).style.modifier == Modifier::Underline {
+                    x += 1;
+                }
+                runs.push((start, x - 1, y));
+            }
+        }
+        // A label wrapped over several lines is made of several runs, spaces at the wrapping
+        // point being dropped: count what is left to match runs with labels.
+        let visible = |x: u16, y: u16, buf: &Buffer| buf.get(x, y).symbol != " ";
+        let mut runs = runs.into_iter();
+        for (id, link) in self.links.iter().enumerate() {
+            let mut remaining = link.label.chars().filter(|c| *c != ' ').count();
+            while remaining > 0 {
+                let (start, end, y) = match runs.next() {
+                    Some(run) => run,
+                    None => return,
+                };
+                remaining = remaining.saturating_sub((start..=end).filter(|x| visible(*x, y, buf)).count());
+                let open = format!("\x1b]8;id={};{}\x1b\\{}", id, link.url, buf.get(start, y).symbol);
+                buf.get_mut(start, y).set_symbol(&open);
+                let close = format!("{}\x1b]8;;\x1b\\", buf.get(end, y).symbol);
+                buf.get_mut(end, y).set_symbol(&close);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use text;
+
+    #[test]
+    fn escaped_braces_do_not_end_styles() {
+        let escaped = escape("a}b{c\\");
+        assert_eq!(escaped, format!("a{}b\\{{c{}", CLOSING_BRACE, BACKSLASH));
+        assert!(!escaped.contains('}'));
+    }
+
+    #[test]
+    fn resume_text_is_escaped_around_links() {
+        assert_eq!(styled("{a} {link=https://a.org l\\1} }", false),
+                   format!("\\{{a{} {{mod=underline l{}1}} <https://a.org> {}", CLOSING_BRACE, BACKSLASH, CLOSING_BRACE));
+    }
+
+    #[test]
+    fn the_cursor_is_found_past_typed_styles() {
+        let text = format!("{}\n{{mod=invert {}}}", escape("{mod=invert x}"), escape("}"));
+        assert_eq!(text::styled_line(&text, "mod=invert"), Some(1));
+    }
+}
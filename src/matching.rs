@@ -0,0 +1,225 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use i18n::Lang;
+use locale::Locale;
+use markup;
+use resume::Resume;
+
+/// Words that say nothing about a position, left out of the keywords.
+const STOPWORDS: &[&str] = &[
+    "a", "about", "above", "across", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at",
+    "be", "been", "being", "both", "but", "by", "can", "could", "do", "does", "each", "etc", "for", "from",
+    "get", "had", "has", "have", "he", "her", "here", "his", "how", "i", "if", "in", "into", "is", "it",
+    "its", "just", "may", "me", "more", "most", "much", "must", "my", "no", "not", "of", "on", "one", "or",
+    "other", "our", "out", "over", "own", "per", "plus", "she", "should", "so", "some", "such", "than",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those", "through", "to",
+    "too", "under", "up", "us", "very", "was", "we", "well", "were", "what", "when", "where", "which",
+    "while", "who", "whom", "why", "will", "with", "within", "would", "you", "your", "yours",
+    // Found in about every job posting
+    "ability", "able", "apply", "benefits", "candidate", "candidates", "company", "environment",
+    "excellent", "experience", "experienced", "good", "great", "help", "ideal", "including", "join",
+    "knowledge", "like", "looking", "love", "new", "nice", "offer", "opportunity", "position",
+    "preferred", "required", "requirements", "responsibilities", "role", "skills", "strong", "team",
+    "understanding", "use", "using", "want", "work", "working", "year", "years",
+];
+
+/// Other names the job postings give to what the resume lists.
+const ALIASES: &[(&str, &str)] = &[
+    ("golang", "go"),
+    ("js", "javascript"),
+    ("node", "node.js"),
+    ("nodejs", "node.js"),
+    ("postgres", "postgresql"),
+    ("k8s", "kubernetes"),
+    ("ror", "rails"),
+];
+
+/// Keywords kept from a job description, the most frequent ones.
+const MAX_KEYWORDS: usize = 30;
+
+/// Where a keyword of the job description was found in the resume.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Found {
+    Skill,
+    Technology,
+    Text,
+    Missing,
+}
+
+pub struct Keyword {
+    pub word: String,
+    /// Times it appears in the job description
+    pub count: usize,
+    pub found: Found,
+}
+
+/// How well the resume fits a job description.
+pub struct Report {
+    /// From the most frequent
+    pub keywords: Vec<Keyword>,
+    /// Names of the programming languages the job description asks for
+    pub skills: Vec<String>,
+    /// Other technologies and areas the job description asks for
+    pub technologies: Vec<String>,
+    /// Indices of the jobs with how often they mention the keywords, weighted by how often the job
+    /// description does, the most relevant first
+    pub roles: Vec<(usize, usize)>,
+}
+
+impl Report {
+    /// Share of the keywords the resume has, counting each as many times as it is used.
+    pub fn score(&self) -> u16 {
+        let total = self.keywords.iter().map(|keyword| keyword.count).sum::<usize>();
+        let found = self.keywords.iter()
+            .filter(|keyword| keyword.found != Found::Missing)
+            .map(|keyword| keyword.count)
+            .sum::<usize>();
+        (found * 100 / total.max(1)) as u16
+    }
+
+    pub fn words(&self, found: Found) -> Vec<&str> {
+        self.keywords.iter()
+            .filter(|keyword| keyword.found == found)
+            .map(|keyword| keyword.word.as_str())
+            .collect()
+    }
+
+    /// Whether the job description asks for the programming language.
+    pub fn has_skill(&self, name: &str) -> bool {
+        self.skills.iter().any(|skill| skill == name)
+    }
+}
+
+/// Lowercase words of a text, keeping the characters of names such as "C++", "C#" or "Node.js".
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#' || c == '.'))
+        .map(|word| word.trim_matches('.').to_lowercase())
+        .filter(|word| !word.is_empty())
+        .map(|word| match ALIASES.iter().find(|&&(alias, _)| alias == word) {
+            Some(&(_, name)) => String::from(name),
+            None => word,
+        })
+        .collect()
+}
+
+/// Whether the words contain the term, which may be made of several words itself.
+fn contains(words: &[String], term: &str) -> bool {
+    let term = self::words(term);
+    !term.is_empty() && words.windows(term.len()).any(|window| window == &term[..])
+}
+
+fn occurrences(words: &[String], word: &str) -> usize {
+    words.iter().filter(|w| *w == word).count()
+}
+
+/// The most frequent words of the text that mean something, in the order they first appear
+/// when they are as frequent.
+fn keywords(words: &[String]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut order = Vec::new();
+    for word in words {
+        // Single letters are only kept for the languages named that way
+        let useful = (word.chars().any(|c| c.is_alphabetic()) && word.chars().count() > 1) || word == "c" || word == "r";
+        if !useful || STOPWORDS.contains(&word.as_str()) {
+            continue;
+        }
+        let count = counts.entry(word).or_insert(0);
+        if *count == 0 {
+            order.push(word.as_str());
+        }
+        *count += 1;
+    }
+    let mut keywords = order.into_iter()
+        .map(|word| (String::from(word), counts[word]))
+        .collect::<Vec<(String, usize)>>();
+    keywords.sort_by_key(|&(_, count)| Reverse(count));
+    keywords.truncate(MAX_KEYWORDS);
+    keywords
+}
+
+/// Compares the resume with a job description.
+pub fn report(resume: &Resume, description: &str) -> Report {
+    let posting = words(description);
+    let skills = resume.skills.iter()
+        .filter(|skill| contains(&posting, &skill.name))
+        .map(|skill| skill.name.clone())
+        .collect::<Vec<String>>();
+    let mut technologies: Vec<String> = Vec::new();
+    let tags = resume.experience.iter().flat_map(|job| job.skills.iter())
+        .chain(resume.all_entries().flat_map(|entry| entry.skills.iter()));
+    for term in resume.others.iter().flat_map(|group| group.items.iter()).chain(tags) {
+        if contains(&posting, term) && !skills.contains(term) && !technologies.contains(term) {
+            technologies.push(term.clone());
+        }
+    }
+    let text = words(&markup::plain(&resume.text()));
+    let keywords = keywords(&posting).into_iter()
+        .map(|(word, count)| {
+            let found = if skills.iter().any(|skill| contains(&words(skill), &word)) {
+                Found::Skill
+            } else if technologies.iter().any(|technology| contains(&words(technology), &word)) {
+                Found::Technology
+            } else if occurrences(&text, &word) > 0 {
+                Found::Text
+            } else {
+                Found::Missing
+            };
+            Keyword { word, count, found }
+        })
+        .collect::<Vec<Keyword>>();
+    let mut roles = resume.experience.iter().enumerate()
+        .map(|(i, job)| {
+            let job_words = words(&format!("{} {} {} {}", job.role, job.company, job.description, job.skills.join(" ")));
+            let relevance = keywords.iter()
+                .filter(|keyword| keyword.found != Found::Missing)
+                .map(|keyword| keyword.count * occurrences(&job_words, &keyword.word))
+                .sum::<usize>();
+            (i, relevance)
+        })
+        .collect::<Vec<(usize, usize)>>();
+    roles.sort_by_key(|&(_, relevance)| Reverse(relevance));
+    Report {
+        keywords,
+        skills,
+        technologies,
+        roles,
+    }
+}
+
+/// Plain text version of the report, as printed by `rust-cv match`.
+pub fn print(resume: &Resume, report: &Report, lang: Lang, locale: &Locale) -> String {
+    let list = |items: &[&str]| if items.is_empty() { String::from(lang.text("none")) } else { items.join(", ") };
+    let skills = report.skills.iter().map(String::as_str).collect::<Vec<&str>>();
+    let technologies = report.technologies.iter().map(String::as_str).collect::<Vec<&str>>();
+    let mut text = lang.format("The resume has {} of the keywords of the job description.", &[&locale.percent(report.score())]);
+    text.push_str("\n\n");
+    for &(label, ref items) in &[("Matching skills", list(&skills)),
+                                 ("Matching technologies", list(&technologies)),
+                                 ("Keywords found in the resume", list(&report.words(Found::Text))),
+                                 ("Missing keywords", list(&report.words(Found::Missing)))] {
+        text.push_str(&format!("{}: {}\n", lang.text(label), items));
+    }
+    text.push_str(&format!("\n{}\n", lang.text("Most relevant roles:")));
+    for (rank, &(i, relevance)) in report.roles.iter().enumerate() {
+        let job = &resume.experience[i];
+        text.push_str(&lang.format("{}. {}, {} (relevance {})", &[&(rank + 1), &job.role, &job.company, &relevance]));
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_are_printed_in_the_language() {
+        let resume = Resume::builtin();
+        let report = report(&resume, "We need Rust and Go experience in Berlin");
+        let text = print(&resume, &report, Lang::German, Locale::of(Lang::German));
+        assert!(text.starts_with(&format!("Der Lebenslauf hat {} der Schlüsselwörter", Locale::of(Lang::German).percent(report.score()))));
+        assert!(text.contains("\nPassende Kenntnisse: Go, Rust\nPassende Technologien: keine\n"));
+        assert!(text.contains("\nRelevanteste Stellen:\n1. "));
+    }
+}
@@ -0,0 +1,260 @@
+use date::{self, Date, Period};
+use resume::{Job, Resume};
+
+/// Months of experience that make a perfect match for a role, company or location.
+const FULL_EXPERIENCE: i32 = 120;
+
+/// Relevance of the level of a language, from what the resume says about it.
+const PROFICIENCIES: [(&str, u16); 8] = [
+    ("native", 100),
+    ("bilingual", 100),
+    ("fluent", 90),
+    ("professional", 80),
+    ("good", 70),
+    ("conversational", 50),
+    ("intermediate", 50),
+    ("basic", 30),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    fn holds(self, level: u16, value: u16) -> bool {
+        match self {
+            Comparison::Less => level < value,
+            Comparison::LessOrEqual => level <= value,
+            Comparison::Equal => level == value,
+            Comparison::GreaterOrEqual => level >= value,
+            Comparison::Greater => level > value,
+        }
+    }
+}
+
+/// What of the jobs a `role:`, `company:` or `location:` filter looks into.
+#[derive(Clone, Copy)]
+enum JobField {
+    Role,
+    Company,
+    Location,
+}
+
+impl JobField {
+    fn of(self, job: &Job) -> &str {
+        match self {
+            JobField::Role => &job.role,
+            JobField::Company => &job.company,
+            JobField::Location => &job.location,
+        }
+    }
+}
+
+enum Term {
+    /// `skill:go`, or `skill:go>=60` for a level
+    Skill(String, Option<(Comparison, u16)>),
+    /// `lang:german`
+    Lang(String),
+    /// `role:engineer`, `company:acme` or `location:berlin`
+    Job(JobField, String),
+    /// Anything else, looked for in the name, the role and the skills
+    Word(String),
+}
+
+/// What the people of a team are searched with, such as `skill:go>=60 lang:german location:berlin`.
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+/// Splits a query at spaces, except in double quotes, as in `skill:"GNU / Linux"`.
+fn tokens(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            _ if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push(token.clone());
+                    token.clear();
+                }
+            }
+            _ => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// A skill and the level it is compared with, from what follows `skill:`.
+fn skill(text: &str) -> Result<Term, String> {
+    let operators = [(">=", Comparison::GreaterOrEqual), ("<=", Comparison::LessOrEqual), (">", Comparison::Greater),
+                     ("<", Comparison::Less), ("=", Comparison::Equal)];
+    for &(operator, comparison) in &operators {
+        if let Some(at) = text.find(operator) {
+            let value = &text[at + operator.len()..];
+            let level = value.parse::<u16>()
+                .map_err(|_| format!("Invalid level in skill:{}, use a number from 0 to 100", text))?;
+            return Ok(Term::Skill(text[..at].to_lowercase(), Some((comparison, level))));
+        }
+    }
+    Ok(Term::Skill(text.to_lowercase(), None))
+}
+
+/// Months spent in the jobs, the months when several overlap counting once.
+fn months(jobs: &[&Job], today: Date) -> i32 {
+    date::total(&jobs.iter().map(|job| job.period).collect::<Vec<Period>>(), today)
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Query, String> {
+        let mut terms = Vec::new();
+        for token in tokens(text) {
+            let (key, value) = match token.find(':') {
+                Some(colon) => (token[..colon].to_lowercase(), &token[colon + 1..]),
+                None => {
+                    terms.push(Term::Word(token.to_lowercase()));
+                    continue;
+                }
+            };
+            if value.is_empty() {
+                return Err(format!("{} needs a value, such as {}:go", key, key));
+            }
+            terms.push(match key.as_str() {
+                "skill" => skill(value)?,
+                "lang" => Term::Lang(value.to_lowercase()),
+                "role" => Term::Job(JobField::Role, value.to_lowercase()),
+                "company" => Term::Job(JobField::Company, value.to_lowercase()),
+                "location" => Term::Job(JobField::Location, value.to_lowercase()),
+                _ => return Err(format!("Unknown filter: {}, use skill, lang, role, company or location", key)),
+            });
+        }
+        Ok(Query { terms })
+    }
+
+    /// How well a person matches, from 0 to 100: the average of how well each term does, a
+    /// skill by its level, a language by how well it is spoken and the jobs by how long they
+    /// lasted, or living there for a location. `None` when a term does not match at all.
+    pub fn score(&self, person: &Resume, today: Date) -> Option<u16> {
+        if self.terms.is_empty() {
+            return Some(100);
+        }
+        let mut total = 0;
+        for term in &self.terms {
+            let relevance = match *term {
+                Term::Skill(ref name, comparison) => {
+                    let level = person.skills.iter()
+                        .find(|skill| skill.name.to_lowercase() == *name)
+                        .map(|skill| skill.level);
+                    match (level, comparison) {
+                        (Some(level), Some((comparison, value))) if comparison.holds(level, value) => level,
+                        (Some(level), None) => level,
+                        // Skills of the other lists have no level, they only match when none is asked
+                        (None, None) if person.others.iter()
+                            .any(|group| group.items.iter().any(|item| item.to_lowercase() == *name)) => 50,
+                        _ => return None,
+                    }
+                }
+                Term::Lang(ref name) => {
+                    let spoken = person.languages.iter().find(|field| field.label.to_lowercase().contains(name.as_str()))?;
+                    let level = spoken.value.to_lowercase();
+                    PROFICIENCIES.iter()
+                        .find(|&&(word, _)| level.contains(word))
+                        .map_or(50, |&(_, relevance)| relevance)
+                }
+                Term::Job(field, ref value) => {
+                    let jobs = person.experience.iter()
+                        .filter(|job| field.of(job).to_lowercase().contains(value.as_str()))
+                        .collect::<Vec<&Job>>();
+                    let based = match field {
+                        JobField::Location => person.based_in().is_some_and(|place| place.to_lowercase().contains(value.as_str())),
+                        _ => false,
+                    };
+                    if based {
+                        100
+                    } else if jobs.is_empty() {
+                        return None;
+                    } else {
+                        (months(&jobs, today).min(FULL_EXPERIENCE) * 100 / FULL_EXPERIENCE) as u16
+                    }
+                }
+                Term::Word(ref word) => {
+                    let mut summary = vec![person.name.to_lowercase()];
+                    summary.extend(person.current_job().map(|job| job.role.to_lowercase()));
+                    summary.extend(person.skills.iter().map(|skill| skill.name.to_lowercase()));
+                    if !summary.iter().any(|text| text.contains(word.as_str())) {
+                        return None;
+                    }
+                    100
+                }
+            };
+            total += u32::from(relevance);
+        }
+        Some((total / self.terms.len() as u32) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use data;
+
+    fn today() -> Date {
+        Date { year: 2020, month: Some(1) }
+    }
+
+    fn score(query: &str) -> Option<u16> {
+        let person = data::load(Path::new("examples/team/jonas.toml")).unwrap();
+        Query::parse(query).unwrap().score(&person, Date { year: 2021, month: Some(1) })
+    }
+
+    #[test]
+    fn queries_are_split_at_spaces_outside_quotes() {
+        assert_eq!(tokens(r#"skill:"GNU / Linux"  lang:german "#), vec!["skill:GNU / Linux", "lang:german"]);
+        assert!(Query::parse("skill:go>=60 lang:german location:berlin role:dev company:acme rust").is_ok());
+        assert_eq!(Query::parse("skill:").err(), Some(String::from("skill needs a value, such as skill:go")));
+        assert_eq!(Query::parse("skill:go>=high").err(),
+                   Some(String::from("Invalid level in skill:go>=high, use a number from 0 to 100")));
+        assert!(Query::parse("city:berlin").err().is_some_and(|error| error.starts_with("Unknown filter: city")));
+    }
+
+    #[test]
+    fn terms_score_by_level_and_time() {
+        assert_eq!(score(""), Some(100));
+        assert_eq!(score("skill:TypeScript"), Some(85));
+        assert_eq!(score("skill:go<50"), Some(40));
+        assert_eq!(score("skill:go>=60"), None);
+        // Skills of the other lists have no level
+        assert_eq!(score("skill:react"), Some(50));
+        assert_eq!(score("skill:react>=10"), None);
+        assert_eq!(score("lang:english"), Some(90));
+        assert_eq!(score("lang:spanish"), Some(30));
+        assert_eq!(score("lang:french"), None);
+        // 13 and 41 months out of 120
+        assert_eq!(score("role:developer"), Some(45));
+        assert_eq!(score("company:elbe"), Some(34));
+        assert_eq!(score("jonas"), Some(100));
+        assert_eq!(score("skill:typescript lang:english"), Some(87));
+        assert_eq!(score("skill:typescript lang:french"), None);
+    }
+
+    #[test]
+    fn locations_match_where_people_live() {
+        let mut person = Resume::builtin();
+        person.experience.retain(|job| !job.location.contains("Berlin"));
+        let query = Query::parse("location:berlin").unwrap();
+        assert_eq!(query.score(&person, today()), Some(100));
+        person.information.retain(|field| field.label != "Currently based in");
+        assert_eq!(query.score(&person, today()), None);
+    }
+}
@@ -0,0 +1,796 @@
+use date::{self, Date, Period};
+use i18n::Lang;
+use locale::Locale;
+use serde::{Deserialize, Serialize};
+
+/// A "Label: value" line, as shown in the About panes.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Field {
+    pub label: String,
+    pub value: String,
+}
+
+/// A titled piece of text: a job, a course, a project...
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Entry {
+    pub title: String,
+    #[serde(flatten)]
+    pub period: Option<Period>,
+    /// Names of the skills put to use
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<String>,
+    /// Variants of the resume it is meant for, all of them when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audiences: Vec<String>,
+    /// One or more paragraphs, separated by a line break
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+}
+
+impl Entry {
+    fn uses(self, skills: &[&str]) -> Entry {
+        Entry {
+            skills: strings(skills),
+            ..self
+        }
+    }
+
+    fn aimed_at(self, audiences: &[&str]) -> Entry {
+        Entry {
+            audiences: strings(audiences),
+            ..self
+        }
+    }
+
+    /// The title followed by the dates, if any, and how long it lasted until `today` when given.
+    pub fn heading(&self, today: Option<Date>, locale: &Locale) -> String {
+        match (self.period, today) {
+            (Some(period), Some(today)) => {
+                format!("{} ({}, {})", self.title, locale.period(period), locale.duration(period.length(today)))
+            }
+            (Some(period), None) => format!("{} ({})", self.title, locale.period(period)),
+            (None, _) => self.title.clone(),
+        }
+    }
+}
+
+/// A position held, with when and where.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Job {
+    #[serde(flatten)]
+    pub period: Period,
+    pub role: String,
+    pub company: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    /// Names of the skills put to use
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<String>,
+    /// Variants of the resume it is meant for, all of them when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audiences: Vec<String>,
+    /// One or more paragraphs, separated by a line break
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+}
+
+impl Job {
+    fn uses(self, skills: &[&str]) -> Job {
+        Job {
+            skills: strings(skills),
+            ..self
+        }
+    }
+
+    fn aimed_at(self, audiences: &[&str]) -> Job {
+        Job {
+            audiences: strings(audiences),
+            ..self
+        }
+    }
+
+    /// Whether it is the same role at the same company, whatever the dates and the description.
+    pub fn is_same(&self, other: &Job) -> bool {
+        self.role.to_lowercase() == other.role.to_lowercase() && self.company.to_lowercase() == other.company.to_lowercase()
+    }
+
+    /// The dates, how long it lasted until `today`, and what the job was.
+    pub fn title(&self, today: Date, locale: &Locale) -> String {
+        format!("{} ({}): {}, {} ({})", locale.period(self.period), locale.duration(self.period.length(today)),
+                self.role, self.company, self.location)
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Skill {
+    pub name: String,
+    /// What kind of work it is used for, such as "Backend"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub category: String,
+    /// Out of 100
+    pub level: u16,
+    /// Still being learnt
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub learning: bool,
+    /// Variants of the resume it is meant for, all of them when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audiences: Vec<String>,
+}
+
+impl Skill {
+    fn aimed_at(self, audiences: &[&str]) -> Skill {
+        Skill {
+            audiences: strings(audiences),
+            ..self
+        }
+    }
+
+    pub fn label(&self, lang: Lang) -> String {
+        if self.learning {
+            lang.format("{} (learning)", &[&self.name])
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+/// How much a skill was put to use in the dated jobs, courses and projects of the resume.
+pub struct Usage {
+    /// Overlapping periods counting once
+    pub months: i32,
+    /// End of the latest period, `None` when it was never used
+    pub last: Option<Date>,
+    /// Whether one of the periods is still going on
+    pub current: bool,
+}
+
+impl Usage {
+    pub fn summary(&self, lang: Lang, locale: &Locale) -> String {
+        if self.current {
+            return lang.format("{}, still in use", &[&locale.duration(self.months)]);
+        }
+        match self.last {
+            Some(last) => lang.format("{}, last used in {}", &[&locale.duration(self.months), &locale.date(last)]),
+            None => String::from(lang.text("no dated use")),
+        }
+    }
+}
+
+/// One of the lists in the "Others" part of the Skills section.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SkillGroup {
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+/// One of the things the ideal role involves.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Goal {
+    pub text: String,
+    /// Variants of the resume it is meant for, all of them when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audiences: Vec<String>,
+}
+
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Objective {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub headline: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub intro: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub goals: Vec<Goal>,
+}
+
+impl Objective {
+    pub fn is_empty(&self) -> bool {
+        self.headline.is_empty() && self.intro.is_empty() && self.goals.is_empty()
+    }
+}
+
+/// The text of the resume in another language: each string of the resume with its translation.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Translation {
+    /// ISO 639-1 code, such as "de"
+    pub lang: String,
+    pub strings: Vec<(String, String)>,
+}
+
+/// A piece of prose of the resume, with the section and the item it is shown in.
+pub struct Passage<'a> {
+    pub section: &'static str,
+    pub item: String,
+    pub text: &'a str,
+}
+
+/// Everything the sections show, independently of how they are laid out.
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct Resume {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub information: Vec<Field>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<Field>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact: Vec<Field>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub about: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<Skill>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub others: Vec<SkillGroup>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub experience: Vec<Job>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub volunteering: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contributions: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Objective::is_empty")]
+    pub objective: Objective,
+    /// The resume is written in English, these are its other languages
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub translations: Vec<Translation>,
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| String::from(*item)).collect()
+}
+
+fn field(label: &str, value: &str) -> Field {
+    Field {
+        label: String::from(label),
+        value: String::from(value),
+    }
+}
+
+fn entry(title: &str, description: &str) -> Entry {
+    Entry {
+        title: String::from(title),
+        period: None,
+        skills: Vec::new(),
+        audiences: Vec::new(),
+        description: String::from(description),
+    }
+}
+
+/// An entry from the start year to the end year, `None` when it is still going on.
+fn dated(start: i32, end: Option<i32>, title: &str, description: &str) -> Entry {
+    Entry {
+        period: Some(Period::new(Date::year(start), end.map(Date::year))),
+        ..entry(title, description)
+    }
+}
+
+/// A job from the start year to the end year, `None` when it is still going on.
+fn job(start: i32, end: Option<i32>, role: &str, company: &str, location: &str, description: &str) -> Job {
+    Job {
+        period: Period::new(Date::year(start), end.map(Date::year)),
+        role: String::from(role),
+        company: String::from(company),
+        location: String::from(location),
+        skills: Vec::new(),
+        audiences: Vec::new(),
+        description: String::from(description),
+    }
+}
+
+fn skill(name: &str, category: &str, level: u16) -> Skill {
+    Skill {
+        name: String::from(name),
+        category: String::from(category),
+        level,
+        learning: false,
+        audiences: Vec::new(),
+    }
+}
+
+fn learning(name: &str, category: &str, level: u16) -> Skill {
+    Skill {
+        learning: true,
+        ..skill(name, category, level)
+    }
+}
+
+/// A goal of the objective for the variants of the resume, all of them when empty.
+fn goal(text: &str, audiences: &[&str]) -> Goal {
+    Goal {
+        text: String::from(text),
+        audiences: strings(audiences),
+    }
+}
+
+/// Whether an item tagged with the audiences belongs in the variant of the resume.
+fn belongs(audiences: &[String], variant: &str) -> bool {
+    audiences.is_empty() || audiences.iter().any(|audience| audience == variant)
+}
+
+/// The items that belong in the variant, those aimed at it first and then those for everyone.
+fn select<T, F>(items: Vec<T>, variant: &str, audiences: F) -> Vec<T>
+where
+    F: Fn(&T) -> &[String],
+{
+    let (aimed, general): (Vec<T>, Vec<T>) = items.into_iter()
+        .filter(|item| belongs(audiences(item), variant))
+        .partition(|item| !audiences(item).is_empty());
+    aimed.into_iter().chain(general).collect()
+}
+
+fn translation(lang: &str, strings: &[(&str, &str)]) -> Translation {
+    Translation {
+        lang: String::from(lang),
+        strings: strings.iter().map(|&(text, translation)| (String::from(text), String::from(translation))).collect(),
+    }
+}
+
+fn group(title: &str, items: &[&str]) -> SkillGroup {
+    SkillGroup {
+        title: String::from(title),
+        items: strings(items),
+    }
+}
+
+impl Resume {
+    /// How long the jobs lasted until `today`, in total and then for each location, the months
+    /// when several jobs overlap counting once.
+    pub fn experience_totals(&self, today: Date, lang: Lang, locale: &Locale) -> Vec<Field> {
+        let periods = self.experience.iter().map(|job| job.period).collect::<Vec<Period>>();
+        let mut totals = vec![field(lang.text("Professional experience"), &locale.duration(date::total(&periods, today)))];
+        let mut locations: Vec<&str> = Vec::new();
+        for job in &self.experience {
+            if !locations.contains(&job.location.as_str()) {
+                locations.push(&job.location);
+            }
+        }
+        for location in locations {
+            let periods = self.experience.iter()
+                .filter(|job| job.location == location)
+                .map(|job| job.period)
+                .collect::<Vec<Period>>();
+            let mut chars = location.chars();
+            let capitalized = chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default();
+            totals.push(field(&capitalized, &locale.duration(date::total(&periods, today))));
+        }
+        totals
+    }
+
+    /// The job still going on or that ended last, the one that started last among those.
+    pub fn current_job(&self) -> Option<&Job> {
+        self.experience.iter().max_by_key(|job| (job.period.end.is_none(), job.period.end, job.period.start))
+    }
+
+    /// Where the person lives, from the "Currently based in" information.
+    pub fn based_in(&self) -> Option<&str> {
+        self.information.iter()
+            .find(|field| field.label.eq_ignore_ascii_case("Currently based in"))
+            .map(|field| field.value.as_str())
+    }
+
+    /// Entries of every section: education, courses, projects, volunteering and contributions.
+    pub fn all_entries(&self) -> impl Iterator<Item = &Entry> {
+        self.education.iter()
+            .chain(&self.courses)
+            .chain(&self.projects)
+            .chain(&self.volunteering)
+            .chain(&self.contributions)
+    }
+
+    /// All the prose of the resume, one piece per line.
+    pub fn text(&self) -> String {
+        let mut text = self.information.iter()
+            .chain(&self.languages)
+            .map(|field| format!("{}: {}", field.label, field.value))
+            .collect::<Vec<String>>();
+        text.push(self.about.clone());
+        for job in &self.experience {
+            text.push(format!("{}, {}: {}", job.role, job.company, job.description));
+        }
+        for entry in self.all_entries() {
+            text.push(format!("{}: {}", entry.title, entry.description));
+        }
+        text.push(self.objective.headline.clone());
+        text.extend(self.objective.goals.iter().map(|goal| goal.text.clone()));
+        text.join("\n")
+    }
+
+    /// The prose of every section, as written in the resume.
+    pub fn passages(&self) -> Vec<Passage<'_>> {
+        let passage = |section, item: &str, text| Passage { section, item: String::from(item), text };
+        let mut passages = vec![passage("About", "About me", self.about.as_str())];
+        for job in &self.experience {
+            passages.push(passage("Experience", &format!("{}, {}", job.role, job.company), &job.description));
+        }
+        for entry in self.education.iter().chain(&self.courses) {
+            passages.push(passage("Education", &entry.title, &entry.description));
+        }
+        for entry in self.projects.iter().chain(&self.volunteering).chain(&self.contributions) {
+            passages.push(passage("Projects", &entry.title, &entry.description));
+        }
+        passages.push(passage("Objective", "Headline", &self.objective.headline));
+        passages.push(passage("Objective", "Introduction", &self.objective.intro));
+        for goal in &self.objective.goals {
+            passages.push(passage("Objective", &goal.text, &goal.text));
+        }
+        passages
+    }
+
+    /// Where the skill was used, from the jobs and the dated entries tagged with it.
+    pub fn usage(&self, skill: &str, today: Date) -> Usage {
+        let tagged = |skills: &[String]| skills.iter().any(|name| name == skill);
+        let mut periods = self.experience.iter()
+            .filter(|job| tagged(&job.skills))
+            .map(|job| job.period)
+            .collect::<Vec<Period>>();
+        periods.extend(self.all_entries().filter(|entry| tagged(&entry.skills)).filter_map(|entry| entry.period));
+        Usage {
+            months: date::total(&periods, today),
+            last: periods.iter().filter_map(|period| period.end).max(),
+            current: periods.iter().any(|period| period.end.is_none()),
+        }
+    }
+
+    /// Names of the variants the items are tagged for, sorted.
+    pub fn audiences(&self) -> Vec<&str> {
+        let mut audiences = self.skills.iter().map(|skill| &skill.audiences)
+            .chain(self.experience.iter().map(|job| &job.audiences))
+            .chain(self.all_entries().map(|entry| &entry.audiences))
+            .chain(self.objective.goals.iter().map(|goal| &goal.audiences))
+            .flat_map(|audiences| audiences.iter().map(String::as_str))
+            .collect::<Vec<&str>>();
+        audiences.sort();
+        audiences.dedup();
+        audiences
+    }
+
+    /// The resume for one audience, such as "devrel": only the items aimed at it or at
+    /// everyone are kept, the ones aimed at it first. Jobs and dated entries stay in
+    /// chronological order.
+    pub fn variant(self, variant: &str) -> Resume {
+        let keep = |entries: Vec<Entry>| entries.into_iter()
+            .filter(|entry| belongs(&entry.audiences, variant))
+            .collect::<Vec<Entry>>();
+        Resume {
+            skills: select(self.skills, variant, |skill| &skill.audiences),
+            experience: self.experience.into_iter().filter(|job| belongs(&job.audiences, variant)).collect(),
+            education: keep(self.education),
+            courses: keep(self.courses),
+            projects: select(self.projects, variant, |entry| &entry.audiences),
+            volunteering: keep(self.volunteering),
+            contributions: keep(self.contributions),
+            objective: Objective {
+                goals: select(self.objective.goals, variant, |goal| &goal.audiences),
+                ..self.objective
+            },
+            ..self
+        }
+    }
+
+    /// The resume in another language, the strings without a translation staying in English.
+    /// Names of people, companies and skills are left as they are.
+    pub fn translated(&self, lang: &str) -> Resume {
+        let strings = match self.translations.iter().find(|translation| translation.lang == lang) {
+            Some(translation) => &translation.strings,
+            None => return self.clone(),
+        };
+        let translate = |text: &mut String| {
+            if let Some((_, translation)) = strings.iter().find(|(original, _)| original == text) {
+                *text = translation.clone();
+            }
+        };
+        let mut resume = self.clone();
+        for field in resume.information.iter_mut().chain(&mut resume.languages).chain(&mut resume.contact) {
+            translate(&mut field.label);
+            translate(&mut field.value);
+        }
+        translate(&mut resume.about);
+        for skill in &mut resume.skills {
+            translate(&mut skill.category);
+        }
+        for group in &mut resume.others {
+            translate(&mut group.title);
+            group.items.iter_mut().for_each(translate);
+        }
+        for job in &mut resume.experience {
+            translate(&mut job.role);
+            translate(&mut job.location);
+            translate(&mut job.description);
+        }
+        let entries = resume.education.iter_mut()
+            .chain(&mut resume.courses)
+            .chain(&mut resume.projects)
+            .chain(&mut resume.volunteering)
+            .chain(&mut resume.contributions);
+        for entry in entries {
+            translate(&mut entry.title);
+            translate(&mut entry.description);
+        }
+        translate(&mut resume.objective.headline);
+        translate(&mut resume.objective.intro);
+        for goal in &mut resume.objective.goals {
+            translate(&mut goal.text);
+        }
+        resume
+    }
+
+    /// The resume shipped with the application.
+    pub fn builtin() -> Resume {
+        Resume {
+            name: String::from("Daisy T"),
+            information: vec![
+                field("Name", "Daisy T"),
+                field("Nationality", "Canadian"),
+                field("Currently based in", "Berlin, Germany"),
+            ],
+            languages: vec![
+                field("English", "Native"),
+                field("French", "Good Knowledge"),
+                field("German", "Good Knowledge"),
+                field("Cantonese", "Conversational"),
+            ],
+            contact: vec![
+                field("Email", "{link=mailto:daisyts@gmx.com daisyts@gmx.com}"),
+                field("Phone", "+49 (0) 176 3163 5400"),
+                field("Website", "{link=https://infoverload.ca/}"),
+                field("Twitter", "{link=https://twitter.com/1nfoverload}"),
+                field("LinkedIn", "{link=http://linkedin.com/in/daisyts}"),
+                field("GitHub", "{link=https://github.com/infoverload}"),
+            ],
+            about: String::from("I am a Software Developer, Technical Writer, Developer Advocate, \
+                                 and Open-Source Enthusiast with experience building web applications.\n\
+                                 I am keen on community work and sharing knowledge and have \
+                                 a particular interest in backend and infrastructure projects.\n\
+                                 My non-technical interests include knitting, baking, \
+                                 & learning new natural languages."),
+            skills: vec![
+                skill("Go", "Backend", 70).aimed_at(&["backend", "devrel"]),
+                skill("JavaScript", "Frontend", 70).aimed_at(&["devrel"]),
+                skill("PHP", "Backend", 70).aimed_at(&["backend"]),
+                skill("Ruby", "Backend", 60).aimed_at(&["backend", "devrel"]),
+                learning("Rust", "Systems", 40),
+                learning("Python", "Backend", 40).aimed_at(&["backend"]),
+            ],
+            others: vec![
+                group("Frameworks", &["Symfony", "Laravel", "Rails"]),
+                group("Technologies", &["GNU / Linux", "OSX", "MySQL", "AJAX", "jQuery", "OOP", "MVC", "Wordpress", "Prometheus", "Docker"]),
+                group("Areas", &["Web Development", "Databases", "Software Engineering", "Monitoring", "Project Management"]),
+                group("Version Control", &["Git", "Mercurial", "SVN"]),
+                group("Task Tracking", &["JIRA", "AutoTask", "Trello", "Asana"]),
+            ],
+            experience: vec![
+                job(2018, None, "Freelancer", "Self-Employed", "remote",
+                    "Perform ongoing consultative and development work ranging from website maintenance/upgrades \
+                     to business strategy and development.")
+                    .uses(&["PHP", "JavaScript"])
+                    .aimed_at(&["backend", "devrel"]),
+                job(2017, None, "Contributor", "Fixate IO", "remote",
+                    "Generate in-depth technical content on an ongoing basis; Conduct market research and perform industry analysis;\n\
+                     Cover wide range of topics relating to software development, memory handling, etc.\n\
+                     Took over two projects to refactor, maintain and add new features.")
+                    .aimed_at(&["writing", "devrel"]),
+                job(2017, Some(2017), "Software Developer", "Project A Services GmbH", "Germany",
+                    "Supported the backend development team by assisting them on various venture projects using Symfony and PHP; \
+                     Collaborated with Product Managers to foster and implement Agile practices; \
+                     Analyzed an existing prototype application, refactored it and added features, \
+                     following the company's best practices and software development principles")
+                    .uses(&["PHP", "Symfony"])
+                    .aimed_at(&["backend"]),
+                job(2014, Some(2015), "Web Developer", "eKomi Ltd", "Germany",
+                    "Supported IT team with both frontend and backend development and debugging tasks while working with large codebase;\n\
+                     Performed both client-facing work (designing customized, responsive review pages for clients) \
+                     and internal tooling for the rest of the team")
+                    .uses(&["PHP", "JavaScript"])
+                    .aimed_at(&["backend"]),
+                job(2011, Some(2012), "Software Developer", "GrantStream Inc", "Canada",
+                    "Responsible for a variety of development projects in grant management software \
+                     including maintenance of PHP applications and MS SQL Server and MySQL databases; \
+                     Configured UI of customized Web applications with PHP, MSSQL, JavaScript, jQuery, CSS")
+                    .uses(&["PHP", "JavaScript", "MySQL", "jQuery"])
+                    .aimed_at(&["backend"]),
+            ],
+            education: vec![
+                dated(2010, Some(2011), "University of Toronto: Certificate in Project Management",
+                      "Studied foundations of project management and how to apply the most effective tools & techniques \
+                       to achieve project objectives"),
+                dated(2004, Some(2009), "University of Western Ontario: Bachelor of Arts",
+                      "Obtained Double Major in Computer Science and Media Studies"),
+                dated(2008, Some(2008), "Stendhal University: Exchange Program",
+                      "Participated in Academic Exchange Program in Grenoble through the University of Western Ontario"),
+            ],
+            courses: vec![
+                dated(2018, Some(2018), "Getting Started with Continuous Delivery",
+                      "O'Reilly Live Online Training Course"),
+                dated(2018, Some(2018), "Practical Kubernetes",
+                      "O'Reilly Live Online Training Course"),
+                dated(2018, Some(2018), "Bill Kennedy's Ultimate Go Workshop",
+                      "A weekend course designed to provide an intensive idiomatic view of Go")
+                    .uses(&["Go"]),
+                dated(2017, Some(2018), "Women Techmakers - JavaScript Crash Course",
+                      "A 12-week lecture course designed to expose participants to multiple levels of the software development stack \
+                       with introductions to Node.js, Vue.js, MongoDB, Unit Testing, CI/CD, Design Patterns, Bridging APIs, and more")
+                    .uses(&["JavaScript"]),
+            ],
+            projects: vec![
+                entry("Observability in the Kitchen",
+                      "This project leverages sensors, open-source software, and Go to improve breadmaking and explores the relationship between \
+                       sourdough cultures, humidity, and temperature and how one can use systems monitoring tools to gain insight into an age-old tradition.")
+                    .uses(&["Go", "Prometheus"])
+                    .aimed_at(&["backend", "devrel", "writing"]),
+                entry("Wortschatz Logger",
+                      "Web application aimed at helping people familiarize themselves with German articles through personal user accounts that allows \
+                       word tracking/categorizing and interactive quizzes. Built with PHP Laravel Framework, PostgreSQL, JavaScript, jQuery, SASS.")
+                    .uses(&["PHP", "Laravel", "JavaScript", "jQuery"])
+                    .aimed_at(&["backend"]),
+                entry("Der Die Das Game",
+                      "HTML5 browser game built with the Phaser.io framework and fully programmed in JavaScript.")
+                    .uses(&["JavaScript"]),
+            ],
+            volunteering: vec![
+                dated(2017, None, "Rails Girls Berlin",
+                      "Volunteer as a coach for the Rails Girls Berlin Community, an organization aimed at mentoring and encouraging women \
+                       with no programming experience to learn the full programming stack and gain practical experience by building their \
+                       own Ruby on Rails app in a safe and welcoming space")
+                    .uses(&["Ruby", "Rails"])
+                    .aimed_at(&["devrel"]),
+            ],
+            contributions: vec![
+                dated(2017, None, "Prometheus",
+                      "Ongoing contributions to a {link=https://prometheus.io/ systems monitoring toolkit} written in Go: \
+                       add new default metric go_info to {link=https://github.com/prometheus/client_golang Go client library}; \
+                       document config options; add new features to command line utility; create custom node exporter for BME280 module; ...")
+                    .uses(&["Go", "Prometheus"])
+                    .aimed_at(&["backend", "devrel", "writing"]),
+            ],
+            objective: Objective {
+                headline: String::from("I am always open to be part of a team that does interesting work. :)"),
+                intro: String::from("My ideal role involves a combination of the following:"),
+                goals: vec![
+                    goal("Solve interesting backend and infrastructure problems", &["backend"]),
+                    goal("Create and improve the tools used during the development", &["backend"]),
+                    goal("Maintain a highly performant and reliable system", &["backend"]),
+                    goal("Create and integrate APIs to expose and extend functionality", &["backend", "devrel"]),
+                    goal("Documentation", &["writing", "devrel"]),
+                    goal("Contribute to open source software", &[]),
+                    goal("Opportunities to attend and speak at conferences", &["devrel"]),
+                    goal("Maintain healthy work-life balance", &[]),
+                    goal("Receive and give mentorshop", &["devrel"]),
+                ],
+            },
+            translations: vec![translation("de", GERMAN)],
+        }
+    }
+}
+
+/// The built-in resume in German.
+const GERMAN: &[(&str, &str)] = &[
+    ("Name", "Name"),
+    ("Nationality", "Staatsangehörigkeit"),
+    ("Canadian", "Kanadisch"),
+    ("Currently based in", "Wohnort"),
+    ("Berlin, Germany", "Berlin, Deutschland"),
+    ("English", "Englisch"),
+    ("Native", "Muttersprache"),
+    ("French", "Französisch"),
+    ("German", "Deutsch"),
+    ("Good Knowledge", "Gute Kenntnisse"),
+    ("Cantonese", "Kantonesisch"),
+    ("Conversational", "Konversationssicher"),
+    ("Email", "E-Mail"),
+    ("Phone", "Telefon"),
+    ("I am a Software Developer, Technical Writer, Developer Advocate, \
+      and Open-Source Enthusiast with experience building web applications.\n\
+      I am keen on community work and sharing knowledge and have \
+      a particular interest in backend and infrastructure projects.\n\
+      My non-technical interests include knitting, baking, \
+      & learning new natural languages.",
+     "Ich bin Softwareentwicklerin, Technische Redakteurin, Developer Advocate \
+      und Open-Source-Begeisterte mit Erfahrung in der Entwicklung von Webanwendungen.\n\
+      Ich engagiere mich gern in der Community, teile mein Wissen und interessiere mich \
+      besonders für Backend- und Infrastrukturprojekte.\n\
+      Außerhalb der Technik stricke und backe ich gern \
+      und lerne neue Sprachen."),
+    ("Systems", "Systeme"),
+    ("Technologies", "Technologien"),
+    ("Areas", "Bereiche"),
+    ("Web Development", "Webentwicklung"),
+    ("Databases", "Datenbanken"),
+    ("Software Engineering", "Softwaretechnik"),
+    ("Project Management", "Projektmanagement"),
+    ("Version Control", "Versionsverwaltung"),
+    ("Task Tracking", "Aufgabenverwaltung"),
+    ("Freelancer", "Freiberuflerin"),
+    ("Perform ongoing consultative and development work ranging from website maintenance/upgrades \
+      to business strategy and development.",
+     "Laufende Beratungs- und Entwicklungsarbeit, von der Wartung und Aktualisierung von Websites \
+      bis zur Geschäftsstrategie und -entwicklung."),
+    ("Contributor", "Autorin"),
+    ("Generate in-depth technical content on an ongoing basis; Conduct market research and perform industry analysis;\n\
+      Cover wide range of topics relating to software development, memory handling, etc.\n\
+      Took over two projects to refactor, maintain and add new features.",
+     "Laufende Erstellung fundierter technischer Inhalte; Marktforschung und Branchenanalysen;\n\
+      Ein breites Spektrum an Themen rund um Softwareentwicklung, Speicherverwaltung usw.\n\
+      Übernahme von zwei Projekten, um sie zu überarbeiten, zu pflegen und um neue Funktionen zu erweitern."),
+    ("Software Developer", "Softwareentwicklerin"),
+    ("Germany", "Deutschland"),
+    ("Supported the backend development team by assisting them on various venture projects using Symfony and PHP; \
+      Collaborated with Product Managers to foster and implement Agile practices; \
+      Analyzed an existing prototype application, refactored it and added features, \
+      following the company's best practices and software development principles",
+     "Unterstützung des Backend-Teams bei verschiedenen Venture-Projekten mit Symfony und PHP; \
+      Einführung und Umsetzung agiler Methoden gemeinsam mit den Product Managern; \
+      Analyse, Überarbeitung und Erweiterung eines bestehenden Prototyps \
+      nach den Best Practices und Entwicklungsprinzipien des Unternehmens"),
+    ("Web Developer", "Webentwicklerin"),
+    ("Supported IT team with both frontend and backend development and debugging tasks while working with large codebase;\n\
+      Performed both client-facing work (designing customized, responsive review pages for clients) \
+      and internal tooling for the rest of the team",
+     "Unterstützung des IT-Teams bei Frontend- und Backend-Entwicklung und Fehlersuche in einer großen Codebasis;\n\
+      Arbeit für Kunden (Gestaltung individueller, responsiver Bewertungsseiten) \
+      und interne Werkzeuge für das restliche Team"),
+    ("Canada", "Kanada"),
+    ("Responsible for a variety of development projects in grant management software \
+      including maintenance of PHP applications and MS SQL Server and MySQL databases; \
+      Configured UI of customized Web applications with PHP, MSSQL, JavaScript, jQuery, CSS",
+     "Verantwortlich für verschiedene Entwicklungsprojekte einer Software zur Fördermittelverwaltung, \
+      einschließlich der Wartung von PHP-Anwendungen sowie MS-SQL-Server- und MySQL-Datenbanken; \
+      Konfiguration der Oberfläche individueller Webanwendungen mit PHP, MSSQL, JavaScript, jQuery, CSS"),
+    ("University of Toronto: Certificate in Project Management", "University of Toronto: Zertifikat in Projektmanagement"),
+    ("Studied foundations of project management and how to apply the most effective tools & techniques \
+      to achieve project objectives",
+     "Grundlagen des Projektmanagements und der Einsatz der wirksamsten Werkzeuge und Techniken, \
+      um Projektziele zu erreichen"),
+    ("University of Western Ontario: Bachelor of Arts", "University of Western Ontario: Bachelor of Arts"),
+    ("Obtained Double Major in Computer Science and Media Studies",
+     "Doppelstudium in Informatik und Medienwissenschaft"),
+    ("Stendhal University: Exchange Program", "Université Stendhal: Austauschprogramm"),
+    ("Participated in Academic Exchange Program in Grenoble through the University of Western Ontario",
+     "Teilnahme am akademischen Austauschprogramm der University of Western Ontario in Grenoble"),
+    ("O'Reilly Live Online Training Course", "Live-Online-Schulung von O'Reilly"),
+    ("A weekend course designed to provide an intensive idiomatic view of Go",
+     "Ein Wochenendkurs für einen intensiven Einblick in idiomatisches Go"),
+    ("Women Techmakers - JavaScript Crash Course", "Women Techmakers - JavaScript-Crashkurs"),
+    ("A 12-week lecture course designed to expose participants to multiple levels of the software development stack \
+      with introductions to Node.js, Vue.js, MongoDB, Unit Testing, CI/CD, Design Patterns, Bridging APIs, and more",
+     "Ein zwölfwöchiger Kurs über mehrere Ebenen der Softwareentwicklung, \
+      mit Einführungen in Node.js, Vue.js, MongoDB, Unit-Tests, CI/CD, Entwurfsmuster, APIs und mehr"),
+    ("Observability in the Kitchen", "Observability in der Küche"),
+    ("This project leverages sensors, open-source software, and Go to improve breadmaking and explores the relationship between \
+      sourdough cultures, humidity, and temperature and how one can use systems monitoring tools to gain insight into an age-old tradition.",
+     "Dieses Projekt nutzt Sensoren, Open-Source-Software und Go, um das Brotbacken zu verbessern, und untersucht den Zusammenhang zwischen \
+      Sauerteigkulturen, Luftfeuchtigkeit und Temperatur, und wie Monitoring-Werkzeuge Einblick in eine uralte Tradition geben können."),
+    ("Web application aimed at helping people familiarize themselves with German articles through personal user accounts that allows \
+      word tracking/categorizing and interactive quizzes. Built with PHP Laravel Framework, PostgreSQL, JavaScript, jQuery, SASS.",
+     "Webanwendung, mit der sich Lernende die deutschen Artikel einprägen, über persönliche Konten, in denen sie \
+      Wörter erfassen und einordnen und interaktive Quizze machen. Entwickelt mit PHP Laravel, PostgreSQL, JavaScript, jQuery, SASS."),
+    ("HTML5 browser game built with the Phaser.io framework and fully programmed in JavaScript.",
+     "HTML5-Browserspiel mit dem Framework Phaser.io, vollständig in JavaScript programmiert."),
+    ("Volunteer as a coach for the Rails Girls Berlin Community, an organization aimed at mentoring and encouraging women \
+      with no programming experience to learn the full programming stack and gain practical experience by building their \
+      own Ruby on Rails app in a safe and welcoming space",
+     "Ehrenamtlicher Coach der Rails Girls Berlin Community, einer Organisation, die Frauen ohne Programmiererfahrung \
+      begleitet und ermutigt, den gesamten Stack kennenzulernen und praktische Erfahrung zu sammeln, indem sie \
+      in einem sicheren und einladenden Umfeld ihre eigene Ruby-on-Rails-Anwendung entwickeln"),
+    ("Ongoing contributions to a {link=https://prometheus.io/ systems monitoring toolkit} written in Go: \
+      add new default metric go_info to {link=https://github.com/prometheus/client_golang Go client library}; \
+      document config options; add new features to command line utility; create custom node exporter for BME280 module; ...",
+     "Laufende Beiträge zu einem in Go geschriebenen {link=https://prometheus.io/ Toolkit für Systemmonitoring}: \
+      neue Standardmetrik go_info in der {link=https://github.com/prometheus/client_golang Go-Client-Bibliothek}; \
+      Dokumentation von Konfigurationsoptionen; neue Funktionen für das Kommandozeilenwerkzeug; eigener Node Exporter für das BME280-Modul; ..."),
+    ("I am always open to be part of a team that does interesting work. :)",
+     "Ich bin immer offen dafür, Teil eines Teams zu sein, das spannende Arbeit macht. :)"),
+    ("My ideal role involves a combination of the following:", "Meine ideale Stelle verbindet Folgendes:"),
+    ("Solve interesting backend and infrastructure problems", "Spannende Backend- und Infrastrukturprobleme lösen"),
+    ("Create and improve the tools used during the development", "Die Werkzeuge der Entwicklung erstellen und verbessern"),
+    ("Maintain a highly performant and reliable system", "Ein leistungsfähiges und zuverlässiges System betreiben"),
+    ("Create and integrate APIs to expose and extend functionality", "APIs erstellen und integrieren, um Funktionen bereitzustellen und zu erweitern"),
+    ("Documentation", "Dokumentation"),
+    ("Contribute to open source software", "Zu Open-Source-Software beitragen"),
+    ("Opportunities to attend and speak at conferences", "Konferenzen besuchen und dort sprechen"),
+    ("Maintain healthy work-life balance", "Eine gesunde Work-Life-Balance"),
+    ("Receive and give mentorshop", "Mentoring erhalten und geben"),
+];
@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use lint::Warning;
+use markup;
+use resume::Resume;
+
+/// Dictionaries built into the binary, in the Hunspell format, see dictionaries/README.md.
+const BUNDLED: &[(&str, &str)] = &[
+    (include_str!("../dictionaries/en_US.aff"), include_str!("../dictionaries/en_US.dic")),
+    (include_str!("../dictionaries/de.aff"), include_str!("../dictionaries/de.dic")),
+];
+/// Word lists of this project, one word per line: names of technologies, companies and
+/// products, and words of resumes the bundled dictionaries lack.
+const WORD_LISTS: &[&str] = &[include_str!("../dictionaries/tech.txt"), include_str!("../dictionaries/resume.txt")];
+
+/// Where Hunspell dictionaries are installed, looked in after the directories of `DICPATH`.
+const SYSTEM_DIRS: &[&str] = &[
+    "/usr/share/hunspell",
+    "/usr/share/myspell",
+    "/usr/share/myspell/dicts",
+    "/usr/local/share/hunspell",
+    "/Library/Spelling",
+];
+/// Names of the installed dictionaries used for each language of the resume, the first one
+/// found being used.
+const SYSTEM_LANGUAGES: &[&[&str]] = &[&["en_US", "en_GB", "en_CA", "en"], &["de_DE", "de_AT", "de_CH", "de"]];
+
+/// Suggestions given for a misspelled word, at most.
+const MAX_SUGGESTIONS: usize = 3;
+/// Characters tried in suggestions when the affix file has no TRY line.
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// How the flags of the words and affixes are written, as set by the FLAG line of an affix file.
+#[derive(Clone, Copy)]
+enum FlagType {
+    /// One character per flag, the default
+    Char,
+    /// Two characters per flag
+    Long,
+    /// Numbers separated by commas
+    Num,
+}
+
+impl FlagType {
+    fn parse(self, flags: &str) -> Vec<String> {
+        match self {
+            FlagType::Char => flags.chars().map(|c| c.to_string()).collect(),
+            FlagType::Long => {
+                let chars = flags.chars().collect::<Vec<char>>();
+                chars.chunks(2).map(|pair| pair.iter().collect()).collect()
+            }
+            FlagType::Num => flags.split(',').map(|flag| String::from(flag.trim())).collect(),
+        }
+    }
+}
+
+/// One character of an affix condition.
+enum Pattern {
+    Any,
+    Char(char),
+    /// Whether the characters are excluded, and the characters
+    Class(bool, Vec<char>),
+}
+
+impl Pattern {
+    fn matches(&self, c: char) -> bool {
+        match *self {
+            Pattern::Any => true,
+            Pattern::Char(expected) => c == expected,
+            Pattern::Class(negated, ref chars) => chars.contains(&c) != negated,
+        }
+    }
+}
+
+fn condition(text: &str) -> Vec<Pattern> {
+    let mut patterns = Vec::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        patterns.push(match c {
+            '.' => Pattern::Any,
+            '[' => {
+                let class = chars.by_ref().take_while(|&c| c != ']').collect::<Vec<char>>();
+                match class.split_first() {
+                    Some((&'^', rest)) => Pattern::Class(true, rest.to_vec()),
+                    _ => Pattern::Class(false, class),
+                }
+            }
+            c => Pattern::Char(c),
+        });
+    }
+    patterns
+}
+
+/// A prefix or suffix rule: `strip` is removed from the stem and `add` is put in its place, for
+/// the stems that match the condition.
+struct Affix {
+    flag: String,
+    /// Whether it combines with the affixes of the other kind
+    cross: bool,
+    strip: String,
+    add: String,
+    condition: Vec<Pattern>,
+}
+
+impl Affix {
+    /// Whether the condition holds at the start of the stem for a prefix, at its end for a suffix.
+    fn applies(&self, stem: &str, prefix: bool) -> bool {
+        let chars = stem.chars().collect::<Vec<char>>();
+        if chars.len() < self.condition.len() {
+            return false;
+        }
+        let start = if prefix { 0 } else { chars.len() - self.condition.len() };
+        self.condition.iter().zip(&chars[start..]).all(|(pattern, &c)| pattern.matches(c))
+    }
+}
+
+/// A dictionary in the format of Hunspell, with its affix file: words carry flags telling which
+/// prefixes and suffixes they take. Compounding and the morphological fields are not supported.
+pub struct Dictionary {
+    words: HashMap<String, Vec<String>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    /// Characters to try when looking for suggestions, the most frequent first
+    try_chars: String,
+    /// Flags of words that are only valid with an affix, inside compounds or not at all
+    restricted: Vec<String>,
+}
+
+impl Dictionary {
+    pub fn parse(aff: &str, dic: &str) -> Result<Dictionary, String> {
+        let mut dictionary = Dictionary {
+            words: HashMap::new(),
+            prefixes: Vec::new(),
+            suffixes: Vec::new(),
+            try_chars: String::from(ALPHABET),
+            restricted: Vec::new(),
+        };
+        let mut flag_type = FlagType::Char;
+        // Whether the affixes of each group combine with the others, from the group headers
+        let mut crosses = HashMap::new();
+        for (number, line) in aff.lines().enumerate() {
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let invalid = || format!("Line {} of the affix file is invalid: {}", number + 1, line);
+            match fields.as_slice() {
+                ["FLAG", "long"] => flag_type = FlagType::Long,
+                ["FLAG", "num"] => flag_type = FlagType::Num,
+                ["FLAG", "UTF-8"] => flag_type = FlagType::Char,
+                ["TRY", chars] => dictionary.try_chars = String::from(*chars),
+                ["NEEDAFFIX", flag] | ["ONLYINCOMPOUND", flag] | ["FORBIDDENWORD", flag] => {
+                    dictionary.restricted.push(String::from(*flag))
+                }
+                [kind, flag, cross, count] if (*kind == "PFX" || *kind == "SFX") && count.parse::<usize>().is_ok() => {
+                    // The header of a group of rules, which follow with the same flag
+                    match *cross {
+                        "Y" | "N" => crosses.insert((*kind, *flag), *cross == "Y"),
+                        _ => return Err(invalid()),
+                    };
+                }
+                [kind, flag, strip, add, rest @ ..] if *kind == "PFX" || *kind == "SFX" => {
+                    let cross = *crosses.get(&(*kind, *flag)).ok_or_else(invalid)?;
+                    // Continuation flags after the affix are not supported and dropped
+                    let add = add.split('/').next().unwrap_or("");
+                    let affix = Affix {
+                        flag: String::from(*flag),
+                        cross,
+                        strip: if *strip == "0" { String::new() } else { String::from(*strip) },
+                        add: if add == "0" { String::new() } else { String::from(add) },
+                        condition: condition(rest.first().cloned().unwrap_or(".")),
+                    };
+                    if *kind == "PFX" {
+                        dictionary.prefixes.push(affix);
+                    } else {
+                        dictionary.suffixes.push(affix);
+                    }
+                }
+                _ => {}
+            }
+        }
+        // The first line of the word list is the number of words
+        for line in dic.lines().skip(1) {
+            let entry = match line.split(&['\t', ' '][..]).next() {
+                Some(entry) if !entry.is_empty() => entry,
+                _ => continue,
+            };
+            let (word, flags) = match entry.find('/') {
+                Some(slash) => (&entry[..slash], flag_type.parse(&entry[slash + 1..])),
+                None => (entry, Vec::new()),
+            };
+            dictionary.words.entry(String::from(word)).or_default().extend(flags);
+        }
+        Ok(dictionary)
+    }
+
+    /// Loads the dictionary from PATH.aff and PATH.dic, in UTF-8 or ISO 8859-1 as given by the SET
+    /// line of the affix file.
+    pub fn load(path: &str) -> Result<Dictionary, String> {
+        let read = |extension: &str| {
+            fs::read(format!("{}.{}", path, extension))
+                .map_err(|error| format!("Cannot read {}.{}: {}", path, extension, error))
+        };
+        let (aff, dic) = (read("aff")?, read("dic")?);
+        let latin1 = String::from_utf8_lossy(&aff).lines().any(|line| {
+            let line = line.trim();
+            line.starts_with("SET") && (line.ends_with("ISO8859-1") || line.ends_with("ISO-8859-1"))
+        });
+        let decode = |bytes: Vec<u8>| if latin1 {
+            bytes.into_iter().map(char::from).collect::<String>()
+        } else {
+            String::from_utf8_lossy(&bytes).into_owned()
+        };
+        Dictionary::parse(&decode(aff), &decode(dic))
+    }
+
+    fn has_flag(&self, stem: &str, flag: &str) -> bool {
+        self.words.get(stem).map(|flags| flags.iter().any(|f| f == flag)).unwrap_or(false)
+    }
+
+    /// Stems the suffixes can come from, with the suffixes.
+    fn suffix_stems<'a>(&'a self, word: &'a str) -> impl Iterator<Item = (String, &'a Affix)> + 'a {
+        self.suffixes.iter()
+            .filter(move |suffix| word.ends_with(&suffix.add) && word.len() > suffix.add.len())
+            .map(move |suffix| (format!("{}{}", &word[..word.len() - suffix.add.len()], suffix.strip), suffix))
+            .filter(|(stem, suffix)| suffix.applies(stem, false))
+    }
+
+    /// Whether the word is in the dictionary, as it is.
+    fn has(&self, word: &str) -> bool {
+        if let Some(flags) = self.words.get(word) {
+            if !flags.iter().any(|flag| self.restricted.contains(flag)) {
+                return true;
+            }
+        }
+        if self.suffix_stems(word).any(|(stem, suffix)| self.has_flag(&stem, &suffix.flag)) {
+            return true;
+        }
+        for prefix in &self.prefixes {
+            if !word.starts_with(&prefix.add) || word.len() <= prefix.add.len() {
+                continue;
+            }
+            let stem = format!("{}{}", prefix.strip, &word[prefix.add.len()..]);
+            if !prefix.applies(&stem, true) {
+                continue;
+            }
+            if self.has_flag(&stem, &prefix.flag) {
+                return true;
+            }
+            // Both a prefix and a suffix, as "un" and "ed" in "unsupported"
+            let both = self.suffix_stems(&stem)
+                .any(|(root, suffix)| prefix.cross && suffix.cross && self.has_flag(&root, &prefix.flag) && self.has_flag(&root, &suffix.flag));
+            if both {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Whether the word is spelled right: lowercase words of the dictionary may also be
+    /// capitalized or in uppercase in the text, like at the start of a sentence.
+    pub fn check(&self, word: &str) -> bool {
+        if self.has(word) {
+            return true;
+        }
+        let lowercase = word.to_lowercase();
+        let mut chars = lowercase.chars();
+        let capitalized = chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default();
+        (word == capitalized || word.to_uppercase() == word) && (self.has(&lowercase) || self.has(&capitalized))
+    }
+}
+
+/// The directories Hunspell dictionaries are looked for in: those of `DICPATH`, then the usual
+/// ones.
+fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = env::var_os("DICPATH").map(|paths| env::split_paths(&paths).collect::<Vec<PathBuf>>()).unwrap_or_default();
+    dirs.extend(SYSTEM_DIRS.iter().map(PathBuf::from));
+    if let Some(home) = env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join("Library/Spelling"));
+    }
+    dirs
+}
+
+/// The installed Hunspell dictionaries for English and German, found in `dirs`, as paths
+/// without their extensions.
+fn find_installed(dirs: &[PathBuf]) -> Vec<String> {
+    SYSTEM_LANGUAGES.iter()
+        .filter_map(|names| {
+            names.iter()
+                .flat_map(|name| dirs.iter().map(move |dir| dir.join(name)))
+                .find(|path| path.with_extension("aff").is_file() && path.with_extension("dic").is_file())
+        })
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+/// The installed Hunspell dictionaries for English and German, such as those of the
+/// hunspell-en-us and hunspell-de-de packages. The bundled German list is short, so German is
+/// only checked in full with these.
+pub fn installed() -> Vec<String> {
+    find_installed(&search_dirs())
+}
+
+/// The dictionaries a word may come from: a resume mixes languages and technical terms.
+pub struct Checker {
+    dictionaries: Vec<Dictionary>,
+}
+
+impl Checker {
+    /// The bundled dictionaries and the ones at the paths, without their extensions.
+    pub fn new(paths: &[String]) -> Result<Checker, String> {
+        let mut dictionaries = Vec::new();
+        for &(aff, dic) in BUNDLED {
+            dictionaries.push(Dictionary::parse(aff, dic)?);
+        }
+        for list in WORD_LISTS {
+            let words = list.lines().filter(|line| !line.starts_with('#')).collect::<Vec<&str>>();
+            dictionaries.push(Dictionary::parse("", &format!("{}\n{}", words.len(), words.join("\n")))?);
+        }
+        for path in paths {
+            dictionaries.push(Dictionary::load(path)?);
+        }
+        Ok(Checker { dictionaries })
+    }
+
+    pub fn check(&self, word: &str) -> bool {
+        self.dictionaries.iter().any(|dictionary| dictionary.check(word))
+    }
+
+    /// Known words one edit away: a letter removed, swapped with the next one, replaced or added.
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let chars = word.chars().collect::<Vec<char>>();
+        let mut tries = self.dictionaries.iter().flat_map(|dictionary| dictionary.try_chars.chars()).collect::<Vec<char>>();
+        tries.dedup();
+        let mut candidates = Vec::new();
+        for i in 0..chars.len() {
+            candidates.push([&chars[..i], &chars[i + 1..]].concat());
+            if i + 1 < chars.len() {
+                let mut swapped = chars.clone();
+                swapped.swap(i, i + 1);
+                candidates.push(swapped);
+            }
+        }
+        for i in 0..=chars.len() {
+            for &c in &tries {
+                if i < chars.len() && chars[i] != c {
+                    candidates.push([&chars[..i], &[c], &chars[i + 1..]].concat());
+                }
+                candidates.push([&chars[..i], &[c], &chars[i..]].concat());
+            }
+        }
+        let mut suggestions: Vec<String> = Vec::new();
+        for candidate in candidates {
+            let candidate = candidate.into_iter().collect::<String>();
+            let seen = suggestions.iter().any(|suggestion| suggestion.to_lowercase() == candidate.to_lowercase());
+            if !seen && self.check(&candidate) {
+                suggestions.push(candidate);
+                if suggestions.len() == MAX_SUGGESTIONS {
+                    break;
+                }
+            }
+        }
+        suggestions
+    }
+
+    /// Whether a piece of text between spaces is right: addresses and words with digits are left
+    /// alone, and words joined by punctuation, such as "CI/CD", are checked on their own when the
+    /// whole is not known.
+    fn misspelled(&self, token: &str) -> Vec<String> {
+        let token = token.trim_matches(|c: char| !c.is_alphanumeric());
+        let skip = token.contains("://") || token.contains('@') || token.contains('_') || token.starts_with("www.")
+            || token.chars().any(|c| c.is_numeric());
+        if token.is_empty() || skip || self.check(token) {
+            return Vec::new();
+        }
+        token.split(|c: char| !(c.is_alphabetic() || c == '\''))
+            .map(|word| word.trim_matches('\''))
+            .filter(|word| word.chars().count() > 1 && !self.check(word))
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// A word of the prose of the resume that is in none of the dictionaries, with where it is.
+pub struct Misspelling {
+    pub section: &'static str,
+    pub item: String,
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+/// The misspelled words of the prose of the resume, with suggestions.
+pub fn misspellings(resume: &Resume, checker: &Checker) -> Vec<Misspelling> {
+    let mut misspellings = Vec::new();
+    for passage in resume.passages() {
+        for token in markup::plain(passage.text).split_whitespace() {
+            for word in checker.misspelled(token) {
+                misspellings.push(Misspelling {
+                    section: passage.section,
+                    item: passage.item.clone(),
+                    suggestions: checker.suggest(&word),
+                    word,
+                });
+            }
+        }
+    }
+    misspellings
+}
+
+pub fn spellcheck(resume: &Resume, checker: &Checker) -> Vec<Warning> {
+    misspellings(resume, checker).into_iter()
+        .map(|misspelling| {
+            let message = if misspelling.suggestions.is_empty() {
+                format!("\"{}\" is not in the dictionary", misspelling.word)
+            } else {
+                format!("\"{}\" is not in the dictionary, did you mean {}?", misspelling.word, misspelling.suggestions.join(", "))
+            };
+            Warning {
+                section: misspelling.section,
+                item: misspelling.item,
+                message,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8\nTRY esianrtolcdugmphbyfvkwz\n\nPFX U Y 1\nPFX U 0 un .\n\n\
+                       SFX D Y 2\nSFX D 0 d e\nSFX D 0 ed [^e]\n\nSFX S Y 1\nSFX S 0 s .\n";
+    const DIC: &str = "3\nsupport/DSU\nminute/S\nreliability\n";
+
+    #[test]
+    fn words_take_their_affixes() {
+        let dictionary = Dictionary::parse(AFF, DIC).unwrap();
+        for word in &["support", "supported", "unsupported", "supports", "minutes", "Minutes", "RELIABILITY"] {
+            assert!(dictionary.check(word), "{}", word);
+        }
+        for word in &["supportd", "reliabilitys", "unminute", "mInutes"] {
+            assert!(!dictionary.check(word), "{}", word);
+        }
+        let checker = Checker {
+            dictionaries: vec![dictionary],
+        };
+        assert_eq!(checker.suggest("minuts"), vec!["minute", "minutes"]);
+    }
+
+    #[test]
+    fn installed_dictionaries_are_found_by_language() {
+        let root = env::temp_dir().join(format!("rust-cv-dictionaries-{}", std::process::id()));
+        let (first, second) = (root.join("first"), root.join("second"));
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+        for path in &[second.join("en_US"), first.join("en_GB"), second.join("de_DE")] {
+            fs::write(path.with_extension("aff"), AFF).unwrap();
+            fs::write(path.with_extension("dic"), DIC).unwrap();
+        }
+        // Only the word list of a dictionary is not enough
+        fs::write(first.join("en_US.dic"), DIC).unwrap();
+        let found = find_installed(&[first.clone(), second.clone()]);
+        fs::remove_dir_all(&root).unwrap();
+        let expected = [second.join("en_US"), second.join("de_DE")].iter().map(|path| path.display().to_string()).collect::<Vec<String>>();
+        assert_eq!(found, expected);
+    }
+}
@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+
+use date::Date;
+use query::Query;
+use resume::Resume;
+
+/// Skills shown for each person in the list, the strongest ones.
+const TOP_SKILLS: usize = 3;
+
+/// The people of a team directory, the list they are picked from and whose resume is shown.
+pub struct Team {
+    pub people: Vec<Resume>,
+    /// What was typed in the search box, a `Query` the people are filtered and ranked with
+    pub query: String,
+    /// Whether keys go to the search box
+    pub searching: bool,
+    /// Position of the selected person in the list
+    pub selected: usize,
+    /// Whether the resume of the selected person is shown instead of the list
+    pub open: bool,
+    /// Person picked to be compared with the next one picked
+    pub marked: Option<Resume>,
+    /// Where the jobs still going on end, for the experience the query asks for
+    today: Date,
+}
+
+impl Team {
+    pub fn new(people: Vec<Resume>, query: &str, today: Date) -> Team {
+        Team {
+            people,
+            query: String::from(query),
+            searching: false,
+            selected: 0,
+            open: false,
+            marked: None,
+            today,
+        }
+    }
+
+    /// The people the list shows, those matching the query with how well they do, the best
+    /// first and then in the order of their files. Nobody when the query is not valid.
+    pub fn listed(&self) -> Vec<(&Resume, u16)> {
+        let query = match Query::parse(&self.query) {
+            Ok(query) => query,
+            Err(_) => return Vec::new(),
+        };
+        let mut listed = self.people.iter()
+            .filter_map(|person| query.score(person, self.today).map(|score| (person, score)))
+            .collect::<Vec<(&Resume, u16)>>();
+        listed.sort_by_key(|&(_, score)| Reverse(score));
+        listed
+    }
+
+    /// What is wrong with the query, if anything.
+    pub fn error(&self) -> Option<String> {
+        Query::parse(&self.query).err()
+    }
+
+    pub fn selection(&self) -> Option<&Resume> {
+        self.listed().get(self.selected).map(|&(person, _)| person)
+    }
+
+    pub fn next(&mut self) {
+        let count = self.listed().len();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let count = self.listed().len();
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    /// Adds a character to the query, selecting the first person matching it.
+    pub fn type_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn erase(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.selected = 0;
+    }
+}
+
+/// What the current job is, empty without any job.
+pub fn role(person: &Resume) -> &str {
+    person.current_job().map_or("", |job| job.role.as_str())
+}
+
+/// Names of the strongest skills, the first listed first among equals.
+pub fn top_skills(person: &Resume) -> Vec<&str> {
+    let mut skills = person.skills.iter().collect::<Vec<_>>();
+    skills.sort_by_key(|skill| Reverse(skill.level));
+    skills.into_iter().take(TOP_SKILLS).map(|skill| skill.name.as_str()).collect()
+}
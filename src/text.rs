@@ -0,0 +1,253 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Zero-width space put after a double-width grapheme in text drawn one grapheme per cell, such
+/// as block titles: the cell it takes is the second column of the grapheme, which the backend
+/// leaves alone.
+const FILLER: &str = "\u{200b}";
+
+/// Which way a grapheme reads.
+#[derive(Clone, Copy, PartialEq)]
+enum Direction {
+    LeftToRight,
+    RightToLeft,
+    /// Spaces and punctuation, which take the direction of what is around them
+    Neutral,
+}
+
+fn direction(grapheme: &str) -> Direction {
+    let c = match grapheme.chars().next() {
+        Some(c) => c,
+        None => return Direction::Neutral,
+    };
+    match c as u32 {
+        // Hebrew, Arabic, Syriac, Thaana, N'Ko and their presentation forms
+        0x0590..=0x07ff | 0x0860..=0x08ff | 0xfb1d..=0xfdff | 0xfe70..=0xfeff => Direction::RightToLeft,
+        _ if c.is_alphanumeric() => Direction::LeftToRight,
+        _ => Direction::Neutral,
+    }
+}
+
+/// Whether a line is written from right to left, as told by its first letter.
+fn right_to_left<'a, I: IntoIterator<Item = &'a str>>(graphemes: I) -> bool {
+    graphemes.into_iter()
+        .map(direction)
+        .find(|&direction| direction != Direction::Neutral)
+        == Some(Direction::RightToLeft)
+}
+
+fn mirrored(grapheme: &str) -> &str {
+    match grapheme {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "<" => ">",
+        ">" => "<",
+        _ => grapheme,
+    }
+}
+
+/// Order in which the items of a right-to-left line are shown, from the left: runs of
+/// left-to-right text, such as names and numbers, keep their order, everything else is reversed.
+fn visual_order(directions: &[Direction]) -> Vec<usize> {
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut i = 0;
+    while i < directions.len() {
+        if directions[i] != Direction::LeftToRight {
+            runs.push(vec![i]);
+            i += 1;
+            continue;
+        }
+        // Neutrals between two left-to-right items belong to the run
+        let mut end = i + 1;
+        let mut j = i + 1;
+        while j < directions.len() && directions[j] != Direction::RightToLeft {
+            if directions[j] == Direction::LeftToRight {
+                end = j + 1;
+            }
+            j += 1;
+        }
+        runs.push((i..end).collect());
+        i = end;
+    }
+    runs.into_iter().rev().flatten().collect()
+}
+
+/// A line in the order it is shown in: as it is, or reversed for a right-to-left script, which
+/// terminals do not lay out by themselves.
+pub fn visual(line: &str) -> String {
+    let graphemes = line.graphemes(true).collect::<Vec<&str>>();
+    if !right_to_left(graphemes.iter().cloned()) {
+        return String::from(line);
+    }
+    let directions = graphemes.iter().map(|grapheme| direction(grapheme)).collect::<Vec<Direction>>();
+    visual_order(&directions).into_iter()
+        .map(|i| if directions[i] == Direction::LeftToRight { graphemes[i] } else { mirrored(graphemes[i]) })
+        .collect()
+}
+
+/// A line for widgets that give each grapheme a cell, such as titles, lists and tables: in the
+/// order it is shown in, with a filler after each double-width grapheme so the cells match the
+/// columns.
+pub fn cells(line: &str) -> String {
+    let mut cells = String::new();
+    for grapheme in visual(line).graphemes(true) {
+        cells.push_str(grapheme);
+        if grapheme.width() > 1 {
+            cells.push_str(FILLER);
+        }
+    }
+    cells
+}
+
+/// A grapheme of `Paragraph` markup, with the style it is shown with, as written in the markup.
+#[derive(Clone, Copy)]
+struct Styled<'a> {
+    grapheme: &'a str,
+    style: Option<&'a str>,
+}
+
+/// Splits `Paragraph` markup, such as `{mod=bold;fg=yellow text}`, into styled graphemes.
+fn parse(text: &str) -> Vec<Styled<'_>> {
+    let mut styled = Vec::new();
+    let mut style = None;
+    let mut graphemes = text.grapheme_indices(true).peekable();
+    while let Some((i, grapheme)) = graphemes.next() {
+        match grapheme {
+            "\\" => {
+                if let Some((_, escaped)) = graphemes.next() {
+                    styled.push(Styled { grapheme: escaped, style });
+                }
+            }
+            "{" if style.is_none() => {
+                let end = text[i..].find(' ').map_or(text.len(), |space| i + space);
+                style = Some(&text[i + 1..end]);
+                while graphemes.peek().is_some_and(|&(j, _)| j <= end) {
+                    graphemes.next();
+                }
+            }
+            "}" if style.is_some() => style = None,
+            _ => styled.push(Styled { grapheme, style }),
+        }
+    }
+    styled
+}
+
+/// Index of the first line of the markup with a grapheme in the given style, such as the one
+/// with the cursor, braces typed by the user not counting.
+pub fn styled_line(text: &str, style: &str) -> Option<usize> {
+    text.lines().position(|line| parse(line).iter().any(|styled| styled.style == Some(style)))
+}
+
+/// Writes styled graphemes back as `Paragraph` markup.
+fn markup(line: &[Styled]) -> String {
+    let mut markup = String::new();
+    let mut current = None;
+    for styled in line {
+        if styled.style != current {
+            if current.is_some() {
+                markup.push('}');
+            }
+            if let Some(style) = styled.style {
+                markup.push_str(&format!("{{{} ", style));
+            }
+            current = styled.style;
+        }
+        // Braces only start a style outside of one
+        if styled.grapheme == "\\" || (current.is_none() && styled.grapheme == "{") {
+            markup.push('\\');
+        }
+        markup.push_str(styled.grapheme);
+    }
+    if current.is_some() {
+        markup.push('}');
+    }
+    markup
+}
+
+/// `Paragraph` markup broken into lines no wider than `width` columns, as the widget would wrap
+/// it but measuring what each grapheme takes on the terminal, so that a double-width one never
+/// goes past the border. Lines in a right-to-left script are reversed and aligned to the right.
+pub fn wrap(text: &str, width: u16) -> String {
+    let width = width as usize;
+    if width == 0 {
+        return String::from(text);
+    }
+    let mut lines: Vec<Vec<Styled>> = vec![Vec::new()];
+    let mut column = 0;
+    // Spaces are dropped at the start of a line the text was wrapped to
+    let mut wrapped = false;
+    for styled in parse(text) {
+        if styled.grapheme == "\n" {
+            lines.push(Vec::new());
+            column = 0;
+            wrapped = false;
+            continue;
+        }
+        let columns = styled.grapheme.width();
+        if column >= width || column + columns > width {
+            lines.push(Vec::new());
+            column = 0;
+            wrapped = true;
+        }
+        if wrapped && styled.grapheme == " " {
+            continue;
+        }
+        wrapped = false;
+        column += columns;
+        if let Some(line) = lines.last_mut() {
+            line.push(styled);
+        }
+    }
+    lines.into_iter()
+        .map(|line| {
+            if !right_to_left(line.iter().map(|styled| styled.grapheme)) {
+                return markup(&line);
+            }
+            let directions = line.iter().map(|styled| direction(styled.grapheme)).collect::<Vec<Direction>>();
+            let columns = line.iter().map(|styled| styled.grapheme.width()).sum::<usize>();
+            let mut shown = vec![Styled { grapheme: " ", style: None }; width.saturating_sub(columns)];
+            shown.extend(visual_order(&directions).into_iter().map(|i| match directions[i] {
+                Direction::LeftToRight => line[i],
+                _ => Styled { grapheme: mirrored(line[i].grapheme), ..line[i] },
+            }));
+            markup(&shown)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_graphemes_never_go_past_the_width() {
+        assert_eq!(wrap("日本語のテキスト", 5), "日本\n語の\nテキ\nスト");
+        assert_eq!(wrap("ab日本", 3), "ab\n日\n本");
+        // The space the line is wrapped at is dropped
+        assert_eq!(wrap("ab cd", 2), "ab\ncd");
+        // Combining marks take no column of their own
+        assert_eq!(wrap("cafe\u{301} cre\u{300}me", 4), "cafe\u{301}\ncre\u{300}m\ne");
+    }
+
+    #[test]
+    fn styles_go_on_over_wrapped_lines() {
+        assert_eq!(wrap("{mod=bold one two} three", 5), "{mod=bold one t}\n{mod=bold wo} th\nree");
+        assert_eq!(wrap("a\\{b \\\\c", 3), "a\\{b\n\\\\c");
+    }
+
+    #[test]
+    fn right_to_left_lines_are_reversed_and_aligned_right() {
+        assert_eq!(wrap("שלום", 6), "  םולש");
+        assert_eq!(wrap("שלום (Rust 2018)", 20), "    (Rust 2018) םולש");
+        assert_eq!(visual("שלום (Rust)"), "(Rust) םולש");
+        assert_eq!(visual("Hello שלום"), "Hello שלום");
+    }
+
+    #[test]
+    fn wide_graphemes_are_followed_by_a_filler_in_cells() {
+        assert_eq!(cells("a日b"), format!("a日{}b", FILLER));
+    }
+}
@@ -0,0 +1,152 @@
+use tui::buffer::Buffer;
+use tui::layout::Rect;
+use tui::style::{Color, Style};
+use tui::widgets::{Block, Widget};
+
+use date::{Date, Period};
+use text;
+
+/// Line of each period in a timeline: a period goes on the first line where it does not overlap
+/// with the periods already there, taking them from the earliest.
+pub fn lanes(periods: &[Period], today: Date) -> Vec<usize> {
+    let mut order = (0..periods.len()).collect::<Vec<usize>>();
+    order.sort_by_key(|&i| periods[i].months(today));
+    let mut lanes = vec![0; periods.len()];
+    // Last month taken on each line so far
+    let mut ends: Vec<i32> = Vec::new();
+    for i in order {
+        let (first, last) = periods[i].months(today);
+        match ends.iter().position(|&end| end < first) {
+            Some(lane) => {
+                ends[lane] = last;
+                lanes[i] = lane;
+            }
+            None => {
+                ends.push(last);
+                lanes[i] = ends.len() - 1;
+            }
+        }
+    }
+    lanes
+}
+
+/// Gantt chart of labelled periods: one bar per period against a scale of years, periods that
+/// overlap being stacked on separate lines.
+pub struct Timeline<'a> {
+    block: Option<Block<'a>>,
+    bars: &'a [(&'a str, Period)],
+    selected: Option<usize>,
+    today: Date,
+    /// How much of the bars is drawn, from 0 to 1
+    progress: f64,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> Default for Timeline<'a> {
+    fn default() -> Timeline<'a> {
+        Timeline {
+            block: None,
+            bars: &[],
+            selected: None,
+            today: Date::today(),
+            progress: 1.0,
+            style: Default::default(),
+            highlight_style: Default::default(),
+        }
+    }
+}
+
+impl<'a> Timeline<'a> {
+    pub fn block(&mut self, block: Block<'a>) -> &mut Timeline<'a> {
+        self.block = Some(block);
+        self
+    }
+
+    pub fn bars(&mut self, bars: &'a [(&'a str, Period)]) -> &mut Timeline<'a> {
+        self.bars = bars;
+        self
+    }
+
+    pub fn select(&mut self, index: Option<usize>) -> &mut Timeline<'a> {
+        self.selected = index;
+        self
+    }
+
+    /// Where the periods that are still going on end.
+    pub fn today(&mut self, today: Date) -> &mut Timeline<'a> {
+        self.today = today;
+        self
+    }
+
+    /// Draws the bars partly, from their start, to have them grow.
+    pub fn progress(&mut self, progress: f64) -> &mut Timeline<'a> {
+        self.progress = progress;
+        self
+    }
+
+    pub fn style(&mut self, style: Style) -> &mut Timeline<'a> {
+        self.style = style;
+        self
+    }
+
+    pub fn highlight_style(&mut self, style: Style) -> &mut Timeline<'a> {
+        self.highlight_style = style;
+        self
+    }
+}
+
+impl<'a> Widget for Timeline<'a> {
+    fn draw(&mut self, area: &Rect, buf: &mut Buffer) {
+        let area = match self.block {
+            Some(ref mut b) => {
+                b.draw(area, buf);
+                b.inner(area)
+            }
+            None => *area,
+        };
+        // At least one line of bars above the scale
+        if area.height < 2 || area.width < 1 || self.bars.is_empty() {
+            return;
+        }
+        let today = self.today;
+        let periods = self.bars.iter().map(|&(_, period)| period).collect::<Vec<Period>>();
+        let months = periods.iter().map(|period| period.months(today)).collect::<Vec<(i32, i32)>>();
+        // The scale covers whole years, from the first one to the last one
+        let first_year = months.iter().map(|&(first, _)| first / 12).min().unwrap_or(today.year);
+        let last_year = months.iter().map(|&(_, last)| last / 12).max().unwrap_or(today.year);
+        let start = first_year * 12;
+        let span = (last_year + 1) * 12 - start;
+        let column = |month: i32| area.left() + ((month - start) * i32::from(area.width) / span) as u16;
+
+        let scale = area.bottom() - 1;
+        for (i, lane) in lanes(&periods, today).into_iter().enumerate() {
+            let y = area.top() + lane as u16;
+            if y >= scale {
+                continue;
+            }
+            let (first, last) = months[i];
+            let left = column(first);
+            let right = column(last + 1).max(left + 1).min(area.right());
+            let right = left + (f64::from(right - left) * self.progress).round().max(1.0) as u16;
+            let style = if self.selected == Some(i) { self.highlight_style } else { self.style };
+            for x in left..right {
+                buf.get_mut(x, y).set_symbol("█").set_style(style);
+            }
+            // The label is written inside the bar, in reverse colors so it stands out
+            let label = format!(" {}", text::cells(self.bars[i].0));
+            let label_style = Style::default().fg(Color::Black).bg(style.fg);
+            buf.set_stringn(left, y, &label, (right - left) as usize, &label_style);
+        }
+
+        let mut free = area.left();
+        for year in first_year..=last_year {
+            let x = column(year * 12);
+            let label = year.to_string();
+            if x >= free && x + label.len() as u16 <= area.right() {
+                buf.set_string(x, scale, &label, &self.style);
+                free = x + label.len() as u16 + 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use termion::event::Key;
+
+use data;
+use editor::{Action, Editor, STEPS};
+use resume::Resume;
+
+/// Where a key typed in the wizard leaves it.
+pub enum Progress {
+    Going,
+    /// The resume was written to the file
+    Done(Box<Resume>),
+    Abandoned,
+}
+
+/// The steps `rust-cv init` walks a new resume through, a form each, before writing it to a
+/// file.
+pub struct Wizard {
+    pub path: String,
+    /// Index of the current step in `STEPS`
+    pub step: usize,
+    /// The form of the current step, with the resume as the steps before left it
+    pub editor: Editor,
+}
+
+impl Wizard {
+    pub fn new(path: String, resume: Resume) -> Wizard {
+        Wizard {
+            path,
+            step: 0,
+            editor: Editor::step(resume, 0),
+        }
+    }
+
+    pub fn last(&self) -> bool {
+        self.step == STEPS.len() - 1
+    }
+
+    /// Handles a key: Ctrl+S goes on to the next step once the form is valid, writing the file
+    /// after the last one, and Esc goes back to the previous step, or leaves from the first.
+    pub fn key(&mut self, key: Key) -> Progress {
+        if key == Key::Esc && self.step > 0 {
+            // What was typed is kept, once it is valid
+            if let Some(resume) = self.editor.apply() {
+                self.go(resume, self.step - 1);
+            }
+            return Progress::Going;
+        }
+        match self.editor.key(key) {
+            Action::Save => {
+                let resume = match self.editor.apply() {
+                    Some(resume) => resume,
+                    None => return Progress::Going,
+                };
+                if !self.last() {
+                    self.go(resume, self.step + 1);
+                    return Progress::Going;
+                }
+                match data::save(&resume, Path::new(&self.path)) {
+                    Ok(()) => Progress::Done(Box::new(resume)),
+                    Err(error) => {
+                        self.editor.status = Some(error);
+                        Progress::Going
+                    }
+                }
+            }
+            Action::Cancel => Progress::Abandoned,
+            Action::Nothing => Progress::Going,
+        }
+    }
+
+    fn go(&mut self, resume: Resume, step: usize) {
+        self.step = step;
+        self.editor = Editor::step(resume, step);
+        // For Esc on the first step to ask before dropping what the other steps were given
+        self.editor.changed = true;
+    }
+}